@@ -0,0 +1,65 @@
+/// Multiplier growth per second in basis points (6% compounded every second)
+///
+/// The live multiplier is `1.06 ^ seconds_since_start`, expressed like every
+/// other multiplier in the contract (100 = 1.00x).
+pub const GROWTH_RATE_BPS: u64 = 600;
+
+/// Fixed-point scale used while compounding (1.000000000x)
+const SCALE: u128 = 1_000_000_000;
+
+/// Upper bound for the scaled multiplier, keeps the math inside u128
+const MAX_SCALED: u128 = SCALE * SCALE;
+
+/// Multiplier the curve saturates at (1,000,000,000.00x), it stays flat after
+pub const MAX_MULTIPLIER: u64 = (MAX_SCALED * 100 / SCALE) as u64;
+
+/// Multiplier (100 = 1.00x) reached `elapsed` seconds after the round started
+pub fn multiplier_at(elapsed: u64) -> u64 {
+    let mut result = SCALE;
+    let mut base = SCALE + (GROWTH_RATE_BPS as u128 * SCALE) / 10_000;
+    let mut exp = elapsed;
+
+    // Exponentiation by squaring, saturating at MAX_SCALED
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = (result * base) / SCALE;
+            if result >= MAX_SCALED {
+                return MAX_MULTIPLIER;
+            }
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = (base * base) / SCALE;
+            if base >= MAX_SCALED {
+                return MAX_MULTIPLIER;
+            }
+        }
+    }
+
+    (result * 100 / SCALE) as u64
+}
+
+/// Seconds after the round started until the curve reaches `multiplier`
+///
+/// `u64::MAX` if the curve saturates below it.
+pub fn time_to_reach(multiplier: u64) -> u64 {
+    if multiplier > MAX_MULTIPLIER {
+        return u64::MAX;
+    }
+
+    // The curve never decreases, so bracket the answer and bisect
+    let mut high = 1;
+    while multiplier_at(high) < multiplier {
+        high *= 2;
+    }
+    let mut low = 0;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if multiplier_at(mid) < multiplier {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
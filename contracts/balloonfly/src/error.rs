@@ -16,7 +16,7 @@ pub enum Error {
     InvalidMultiplier = 5,
     /// Invalid bet amount
     InvalidBetAmount = 6,
-    /// Bet already placed for this round
+    /// Player already placed the max number of bets in this round
     BetAlreadyPlaced = 7,
     /// Bet not found
     BetNotFound = 8,
@@ -30,5 +30,55 @@ pub enum Error {
     TransferFailed = 12,
     /// Admin not initialized
     AdminNotInitialized = 13,
+    /// Round is neither cancelled nor timed out
+    RefundNotAvailable = 14,
+    /// Invalid configuration values
+    InvalidConfig = 15,
+    /// Bankroll can't cover the worst-case payout of the round
+    InsufficientBankroll = 16,
+    /// Invalid amount
+    InvalidAmount = 17,
+    /// Liquidity can't change while rounds are open
+    LiquidityLocked = 18,
+    /// Not enough LP shares
+    InsufficientShares = 19,
+    /// Contract is paused
+    ContractPaused = 20,
+    /// Contract is not paused
+    ContractNotPaused = 21,
+    /// Previous round is still waiting or in progress
+    PreviousRoundOpen = 22,
+    /// No seed chain committed
+    SeedChainNotFound = 23,
+    /// Every seed of the chain has been revealed
+    SeedChainExhausted = 24,
+    /// Bet has no seed commitment to reveal
+    SeedNotCommitted = 25,
+    /// Client seed doesn't match its commitment
+    InvalidClientSeed = 26,
+    /// Round stopped taking bets for the reveal phase
+    BettingClosed = 27,
+    /// Cash-out fraction must be between 1 and 10000 basis points
+    InvalidCashOutFraction = 28,
+    /// Bet reached the max number of partial cash-outs
+    TooManyPartialCashOuts = 29,
+    /// No settled withdrawal or unpaid payout to claim
+    NothingToClaim = 30,
+    /// Round hasn't reached its crash point yet
+    RoundNotCrashed = 31,
+    /// Round reached the max number of bets
+    RoundFull = 32,
+    /// Round has committed bets, betting must be closed for reveals first
+    RevealPhaseRequired = 33,
+    /// Reveal window of the round hasn't ended yet
+    RevealWindowOpen = 34,
+    /// Legacy round hasn't ended or legacy bet is still active
+    UnsettledLegacyEntry = 35,
+    /// No token stored and none passed to `migrate`
+    TokenNotInitialized = 36,
+    /// Round has client seeds, so the operator may already know its crash point
+    ClientSeedsCollected = 37,
+    /// Seed chain still has unrevealed seeds
+    SeedChainActive = 38,
 }
 
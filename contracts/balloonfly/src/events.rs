@@ -0,0 +1,266 @@
+use soroban_sdk::{contractevent, Address, BytesN};
+
+use crate::types::{Config, Role};
+
+/// Emitted when a round is created, topics: `["round_created", round_id]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundCreated {
+    #[topic]
+    pub round_id: u64,
+    pub server_seed_hash: BytesN<32>,
+}
+
+/// Emitted when the operator commits a seed chain,
+/// topics: `["seed_chain_committed"]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeedChainCommitted {
+    pub terminal_hash: BytesN<32>,
+    pub length: u32,
+}
+
+/// Emitted when a round starts, topics: `["round_started", round_id]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundStarted {
+    #[topic]
+    pub round_id: u64,
+    pub server_seed: BytesN<32>,
+    pub crash_multiplier: u64,
+}
+
+/// Emitted when a round stops taking bets and committed client seeds can be
+/// revealed, topics: `["betting_closed", round_id]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BettingClosed {
+    #[topic]
+    pub round_id: u64,
+}
+
+/// Emitted when a bet is placed, topics: `["bet_placed", round_id, player]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BetPlaced {
+    #[topic]
+    pub round_id: u64,
+    #[topic]
+    pub player: Address,
+    pub bet_id: u64,
+    pub amount: i128,
+    pub auto_cash_out: Option<u64>,
+}
+
+/// Emitted when a bet is cashed out, manually or by its auto cash-out target,
+/// topics: `["cashed_out", round_id, player]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CashedOut {
+    #[topic]
+    pub round_id: u64,
+    #[topic]
+    pub player: Address,
+    pub bet_id: u64,
+    pub multiplier: u64,
+    pub payout: i128,
+}
+
+/// Emitted when part of a bet's stake is cashed out and the rest keeps riding,
+/// topics: `["partial_cashed_out", round_id, player]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PartialCashedOut {
+    #[topic]
+    pub round_id: u64,
+    #[topic]
+    pub player: Address,
+    pub bet_id: u64,
+    pub amount: i128,
+    pub multiplier: u64,
+    pub payout: i128,
+}
+
+/// Emitted when a player reveals a committed client seed,
+/// topics: `["seed_revealed", round_id, player]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeedRevealed {
+    #[topic]
+    pub round_id: u64,
+    #[topic]
+    pub player: Address,
+    pub bet_id: u64,
+    pub client_seed: BytesN<32>,
+}
+
+/// Emitted when a bet is voided at round start because its committed seed
+/// wasn't revealed, topics: `["bet_forfeited", round_id, player]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BetForfeited {
+    #[topic]
+    pub round_id: u64,
+    #[topic]
+    pub player: Address,
+    pub bet_id: u64,
+    pub refund: i128,
+    pub penalty: i128,
+}
+
+/// Emitted when a round is finalized, topics: `["round_finalized", round_id]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundFinalized {
+    #[topic]
+    pub round_id: u64,
+    pub crash_multiplier: u64,
+    pub total_payout: i128,
+    pub winner_count: u32,
+    pub loser_count: u32,
+    pub house_profit: i128,
+}
+
+/// Emitted when a round is cancelled, by the admin or by a timed out refund,
+/// topics: `["round_cancelled", round_id]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundCancelled {
+    #[topic]
+    pub round_id: u64,
+}
+
+/// Emitted when a bet is refunded, topics: `["refund_claimed", round_id, player]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundClaimed {
+    #[topic]
+    pub round_id: u64,
+    #[topic]
+    pub player: Address,
+    pub bet_id: u64,
+    pub amount: i128,
+}
+
+/// Emitted when a provider adds liquidity, topics: `["liquidity_deposited", provider]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LiquidityDeposited {
+    #[topic]
+    pub provider: Address,
+    pub amount: i128,
+    pub shares: i128,
+}
+
+/// Emitted when a withdrawal waits for open rounds to close,
+/// topics: `["withdrawal_queued", provider]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalQueued {
+    #[topic]
+    pub provider: Address,
+    pub shares: i128,
+}
+
+/// Emitted when a provider's shares are burned at the bankroll's net asset
+/// value, topics: `["liquidity_withdrawn", provider]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LiquidityWithdrawn {
+    #[topic]
+    pub provider: Address,
+    pub shares: i128,
+    pub amount: i128,
+}
+
+/// Emitted when a provider claims a settled withdrawal,
+/// topics: `["withdrawal_claimed", provider]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalClaimed {
+    #[topic]
+    pub provider: Address,
+    pub amount: i128,
+}
+
+/// Emitted when a player claims payouts credited to them,
+/// topics: `["payout_claimed", player]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutClaimed {
+    #[topic]
+    pub player: Address,
+    pub amount: i128,
+}
+
+/// Emitted when the admin proposes a new admin,
+/// topics: `["admin_proposed", current_admin, new_admin]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposed {
+    #[topic]
+    pub current_admin: Address,
+    #[topic]
+    pub new_admin: Address,
+}
+
+/// Emitted when the proposed admin accepts,
+/// topics: `["admin_transferred", previous_admin, new_admin]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferred {
+    #[topic]
+    pub previous_admin: Address,
+    #[topic]
+    pub new_admin: Address,
+}
+
+/// Emitted when the admin assigns a role, topics: `["role_set", account]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleSet {
+    #[topic]
+    pub account: Address,
+    pub role: Role,
+}
+
+/// Emitted when the pauser pauses the contract, topics: `["paused", pauser]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Paused {
+    #[topic]
+    pub pauser: Address,
+}
+
+/// Emitted when the pauser unpauses the contract, topics: `["unpaused", pauser]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Unpaused {
+    #[topic]
+    pub pauser: Address,
+}
+
+/// Emitted when the admin replaces the contract code,
+/// topics: `["contract_upgraded"]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractUpgraded {
+    pub new_wasm_hash: BytesN<32>,
+}
+
+/// Emitted when `migrate` brings storage to a new schema version,
+/// topics: `["storage_migrated"]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageMigrated {
+    pub from_version: u32,
+    pub to_version: u32,
+}
+
+/// Emitted when the treasurer updates the game configuration,
+/// topics: `["config_updated"]`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigUpdated {
+    pub config: Config,
+}
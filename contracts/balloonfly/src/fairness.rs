@@ -0,0 +1,97 @@
+use soroban_sdk::{Bytes, BytesN, Env, Vec};
+
+use crate::types::RoundEntropy;
+
+/// SHA-256 block size in bytes, used for HMAC key padding
+const BLOCK_SIZE: usize = 64;
+
+/// Number of hash bits used to draw the crash point (fits an f64 mantissa,
+/// so off-chain verifiers in JavaScript get the exact same result)
+const HASH_BITS: u32 = 52;
+
+/// Round hash formula, published through `verify_round`
+pub const ROUND_HASH_FORMULA: &str =
+    "HMAC_SHA256(server_seed, client_seed_1 || ... || client_seed_n)";
+
+/// Round hash formula with ledger entropy mixed in, published through
+/// `verify_round`
+///
+/// The entropy only keeps the operator from knowing the crash point when
+/// committing the server seed. It doesn't stop a re-roll: the operator can
+/// call `start_round` from a wrapper contract that reverts on a bad crash
+/// point and retry in a later ledger.
+pub const ROUND_HASH_WITH_ENTROPY_FORMULA: &str =
+    "HMAC_SHA256(server_seed, client_seed_1 || ... || client_seed_n || entropy), \
+     entropy = SHA256(u32_be(ledger_sequence) || u64_be(ledger_timestamp) || prng_seed)";
+
+/// HMAC-SHA256 as defined in RFC 2104
+pub fn hmac_sha256(env: &Env, key: &BytesN<32>, message: &Bytes) -> BytesN<32> {
+    let mut ipad = [0x36u8; BLOCK_SIZE];
+    let mut opad = [0x5cu8; BLOCK_SIZE];
+    for (i, byte) in key.to_array().iter().enumerate() {
+        ipad[i] ^= byte;
+        opad[i] ^= byte;
+    }
+
+    let mut inner = Bytes::from_array(env, &ipad);
+    inner.append(message);
+    let inner_hash: BytesN<32> = env.crypto().sha256(&inner).into();
+
+    let mut outer = Bytes::from_array(env, &opad);
+    outer.append(&inner_hash.into());
+    env.crypto().sha256(&outer).into()
+}
+
+/// Round hash: HMAC-SHA256 keyed with the server seed over the client seeds
+///
+/// `hash = HMAC_SHA256(server_seed, client_seed_1 || client_seed_2 || ...)`,
+/// with the round's ledger entropy appended to the message if there is one.
+pub fn round_hash(
+    env: &Env,
+    server_seed: &BytesN<32>,
+    client_seeds: &Vec<BytesN<32>>,
+    entropy: Option<&RoundEntropy>,
+) -> BytesN<32> {
+    let mut message = Bytes::new(env);
+    for client_seed in client_seeds.iter() {
+        message.append(&client_seed.into());
+    }
+    if let Some(entropy) = entropy {
+        message.append(&entropy.entropy.clone().into());
+    }
+    hmac_sha256(env, server_seed, &message)
+}
+
+/// Draw entropy from the ledger and the host PRNG at round start
+pub fn draw_entropy(env: &Env) -> RoundEntropy {
+    let ledger_sequence = env.ledger().sequence();
+    let ledger_timestamp = env.ledger().timestamp();
+    let prng_seed: BytesN<32> = env.prng().gen();
+
+    let mut preimage = Bytes::from_array(env, &ledger_sequence.to_be_bytes());
+    preimage.extend_from_array(&ledger_timestamp.to_be_bytes());
+    preimage.append(&prng_seed.clone().into());
+
+    RoundEntropy {
+        ledger_sequence,
+        ledger_timestamp,
+        prng_seed,
+        entropy: env.crypto().sha256(&preimage).into(),
+    }
+}
+
+/// Map a round hash to a crash multiplier (100 = 1.00x)
+///
+/// Standard crash-game distribution: take the first 52 bits of the hash as
+/// `h`, with `e = 2^52` the crash point is `(1 - edge) * e / (e - h)`,
+/// floored to two decimals, never below 1.00x and never above `max_multiplier`.
+pub fn crash_multiplier(hash: &BytesN<32>, house_edge_bps: u32, max_multiplier: u64) -> u64 {
+    let bytes = hash.to_array();
+    let mut first = [0u8; 8];
+    first.copy_from_slice(&bytes[..8]);
+    let h = (u64::from_be_bytes(first) >> (64 - HASH_BITS)) as u128;
+    let e = 1u128 << HASH_BITS;
+
+    let multiplier = ((10_000 - house_edge_bps as u128) * e) / (100 * (e - h));
+    (multiplier.min(max_multiplier as u128) as u64).max(100)
+}
@@ -1,17 +1,42 @@
 #![no_std]
 
+mod curve;
 mod error;
+mod events;
+mod fairness;
+mod stats;
 mod storage;
 mod types;
+mod vault;
 
-use soroban_sdk::{contract, contractimpl, Address, Bytes, Env, BytesN, Vec};
+use soroban_sdk::{contract, contractimpl, token, Address, Bytes, Env, BytesN, String, Vec};
 
 pub use error::Error;
-pub use types::{Bet, BetStatus, Pool, Round, RoundStatus};
+pub use types::{
+    Bet, BetStatus, ChainSource, Config, EntropySource, PartialCashOut, PlayerStats, Pool, Role,
+    Round, RoundEntropy, RoundStatus, RoundVerification, SeedChain, SeedChainLink,
+};
 
+use events::{
+    AdminProposed, AdminTransferred, BetForfeited, BetPlaced, BettingClosed, CashedOut,
+    ConfigUpdated, ContractUpgraded, LiquidityDeposited, PartialCashedOut, Paused, PayoutClaimed,
+    RefundClaimed, RoleSet, RoundCancelled, RoundCreated, RoundFinalized, RoundStarted,
+    SeedChainCommitted, SeedRevealed, StorageMigrated, Unpaused, WithdrawalQueued,
+};
 use storage::{
-    get_admin, get_bet, get_pool, get_round, has_admin, set_admin, set_bet, set_pool, set_round,
-    HOUSE_EDGE_BPS,
+    add_player_bet, add_player_round_bet, add_round_bet, default_config, extend_instance,
+    extend_round_ttl, get_admin, get_bet, get_config, get_last_round_id, get_lp_shares,
+    get_open_rounds, get_pending_admin, get_player_bet_chunk, get_player_bet_count,
+    get_player_round_bets, get_player_stats, get_pool, get_revealed_seeds, get_role, get_round,
+    get_round_bets, get_round_chain_link, get_round_entropy, get_seed_chain, get_seed_commitment,
+    get_token, get_unpaid_payout, get_version, has_admin, has_bet, has_role, has_round, has_token,
+    is_paused, migrate_legacy_entry, remove_pending_admin, remove_seed_commitment, set_admin,
+    set_bet, set_config, set_last_round_id, set_lp_shares, set_paused, set_pending_admin, set_pool,
+    set_revealed_seeds, set_role, set_round, set_round_chain_link, set_round_entropy,
+    set_seed_chain, set_seed_commitment, set_token, set_unpaid_payout, set_version,
+    upgrade_bet_layout, upgrade_config_layout, upgrade_pool_layout, upgrade_round_layout,
+    upgrade_withdrawal_queue, ENDED_ROUND_TTL, MAX_BETS_PER_ROUND, MAX_PAGE_SIZE,
+    MAX_PARTIAL_CASH_OUTS, PERSISTENT_BUMP_AMOUNT, PLAYER_BETS_CHUNK_SIZE, SCHEMA_VERSION,
 };
 
 #[contract]
@@ -19,108 +44,522 @@ pub struct BalloonFlyContract;
 
 #[contractimpl]
 impl BalloonFlyContract {
-    /// Initialize the contract with admin address
-    pub fn __constructor(env: Env, admin: Address) {
+    /// Initialize the contract with admin address and the token used for bets
+    /// (the Stellar Asset Contract for XLM in production)
+    ///
+    /// The admin starts out holding every role.
+    pub fn __constructor(env: Env, admin: Address, token: Address) {
         if has_admin(&env) {
             panic!("Already initialized");
         }
         
         admin.require_auth();
         set_admin(&env, &admin);
+        set_token(&env, &token);
+        set_config(&env, &default_config());
+        set_version(&env, SCHEMA_VERSION);
+        for role in [Role::Operator, Role::Treasurer, Role::Pauser] {
+            set_role(&env, role, &admin);
+        }
         
         // Initialize pool
         let pool = Pool {
             total_bets: 0,
             total_payouts: 0,
             total_house_earnings: 0,
+            bankroll: 0,
+            total_shares: 0,
         };
         set_pool(&env, &pool);
+        extend_instance(&env);
     }
 
     /// Get current admin address
     pub fn admin(env: Env) -> Address {
+        extend_instance(&env);
         get_admin(&env)
     }
 
-    /// Create a new round (admin only)
-    /// 
-    /// Security: Only admin can create rounds to prevent spam
-    pub fn create_round(
-        env: Env,
-        round_id: u64,
-        server_seed_hash: BytesN<32>,
-    ) -> Result<Round, Error> {
+    /// Propose a new admin (admin only)
+    ///
+    /// The transfer only happens once the new admin calls `accept_admin`,
+    /// so a typo can't lock the contract. Proposing again replaces the
+    /// previous proposal.
+    pub fn propose_admin(env: Env, new_admin: Address) {
+        extend_instance(&env);
         let admin = get_admin(&env);
         admin.require_auth();
 
-        // Prevent round ID reuse (security)
-        if env.storage().persistent().has(&round_id) {
-            return Err(Error::RoundAlreadyExists);
-        }
-
-        let round = Round {
-            id: round_id,
-            status: RoundStatus::Waiting,
-            server_seed_hash,
-            crash_multiplier: 0, // Will be set when round starts
-            created_at: env.ledger().timestamp(),
-            started_at: 0,
-            ended_at: 0,
-            total_bet_amount: 0,
-            total_payout: 0,
-            bet_count: 0,
-            client_seeds: Vec::new(&env),
-        };
+        set_pending_admin(&env, &new_admin);
+        AdminProposed {
+            current_admin: admin,
+            new_admin,
+        }
+        .publish(&env);
+    }
 
-        set_round(&env, round_id, &round);
-        Ok(round)
+    /// Accept a pending admin transfer (proposed admin only)
+    pub fn accept_admin(env: Env) -> Result<(), Error> {
+        extend_instance(&env);
+        let new_admin = get_pending_admin(&env).ok_or(Error::Unauthorized)?;
+        new_admin.require_auth();
+
+        let previous_admin = get_admin(&env);
+        set_admin(&env, &new_admin);
+        remove_pending_admin(&env);
+        AdminTransferred {
+            previous_admin,
+            new_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Get admin address waiting to accept the transfer, if any
+    pub fn pending_admin(env: Env) -> Option<Address> {
+        extend_instance(&env);
+        get_pending_admin(&env)
+    }
+
+    /// Assign a role to an address (admin only)
+    pub fn set_role(env: Env, role: Role, account: Address) {
+        extend_instance(&env);
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        set_role(&env, role, &account);
+        RoleSet { account, role }.publish(&env);
+    }
+
+    /// Get address holding a role
+    pub fn get_role(env: Env, role: Role) -> Address {
+        extend_instance(&env);
+        get_role(&env, role)
+    }
+
+    /// Pause the contract (pauser only)
+    ///
+    /// Blocks new rounds, bets and liquidity deposits, and moving a round to
+    /// its reveal phase or start. Cash-outs, seed reveals, finalization,
+    /// refunds, claims, withdrawals and views keep working so players and
+    /// liquidity providers can always exit.
+    pub fn pause(env: Env) -> Result<(), Error> {
+        extend_instance(&env);
+        let pauser = get_role(&env, Role::Pauser);
+        pauser.require_auth();
+
+        if is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        set_paused(&env, true);
+        Paused { pauser }.publish(&env);
+        Ok(())
+    }
+
+    /// Unpause the contract (pauser only)
+    pub fn unpause(env: Env) -> Result<(), Error> {
+        extend_instance(&env);
+        let pauser = get_role(&env, Role::Pauser);
+        pauser.require_auth();
+
+        if !is_paused(&env) {
+            return Err(Error::ContractNotPaused);
+        }
+        set_paused(&env, false);
+        Unpaused { pauser }.publish(&env);
+        Ok(())
+    }
+
+    /// Check if the contract is paused
+    pub fn paused(env: Env) -> bool {
+        extend_instance(&env);
+        is_paused(&env)
+    }
+
+    /// Get token contract address used for bets and payouts
+    pub fn token(env: Env) -> Address {
+        extend_instance(&env);
+        get_token(&env)
+    }
+
+    /// Update game configuration (treasurer only)
+    ///
+    /// Rounds keep the house edge and max multiplier they were created with.
+    pub fn set_config(env: Env, config: Config) -> Result<(), Error> {
+        extend_instance(&env);
+        let treasurer = get_role(&env, Role::Treasurer);
+        treasurer.require_auth();
+
+        // House edge below 100%, sane bet bounds, crash cap above 1.00x and
+        // within the curve's range, rounds can't be refunded before the curve
+        // reaches the cap, reveal window shorter than the waiting timeout,
+        // rounds small enough to finalize in one transaction
+        if config.house_edge_bps >= 10_000
+            || config.min_bet <= 0
+            || config.min_bet > config.max_bet
+            || config.max_multiplier <= 100
+            || config.max_multiplier > curve::MAX_MULTIPLIER
+            || config.max_client_seeds == 0
+            || config.waiting_timeout == 0
+            || config.in_progress_timeout <= curve::time_to_reach(config.max_multiplier)
+            || config.max_exposure_bps == 0
+            || config.max_exposure_bps > 10_000
+            || config.unrevealed_penalty_bps > 10_000
+            || config.max_bets_per_player == 0
+            || config.max_bets_per_round == 0
+            || config.max_bets_per_round > MAX_BETS_PER_ROUND
+            || config.min_reveal_window >= config.waiting_timeout
+        {
+            return Err(Error::InvalidConfig);
+        }
+
+        set_config(&env, &config);
+        ConfigUpdated { config }.publish(&env);
+        Ok(())
+    }
+
+    /// Get game configuration
+    pub fn get_config(env: Env) -> Config {
+        extend_instance(&env);
+        get_config(&env)
+    }
+
+    /// Deposit liquidity into the house bankroll
+    ///
+    /// Mints LP shares priced at the bankroll's net asset value, so every
+    /// provider shares the house P&L in proportion to their shares. Locked
+    /// while rounds are open, since their outcome isn't settled yet, and
+    /// while the contract is paused.
+    pub fn deposit_liquidity(env: Env, provider: Address, amount: i128) -> Result<i128, Error> {
+        extend_instance(&env);
+        provider.require_auth();
+        when_not_paused(&env)?;
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if get_open_rounds(&env) > 0 {
+            return Err(Error::LiquidityLocked);
+        }
+
+        let shares = vault::shares_for_deposit(&env, amount)?;
+        if shares <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        transfer(&env, &provider, &env.current_contract_address(), amount)?;
+
+        let mut pool = get_pool(&env);
+        pool.bankroll += amount;
+        pool.total_shares += shares;
+        set_pool(&env, &pool);
+        set_lp_shares(&env, &provider, get_lp_shares(&env, &provider) + shares);
+
+        LiquidityDeposited {
+            provider,
+            amount,
+            shares,
+        }
+        .publish(&env);
+
+        Ok(shares)
     }
 
-    /// Start the round with server seed (admin only)
+    /// Withdraw liquidity by burning LP shares
+    ///
+    /// Paid out right away when no round is open. Otherwise the shares are
+    /// queued and burned once the last open round is finalized or cancelled,
+    /// at the settled net asset value; returns 0 in that case and the amount
+    /// is then collected with `claim_withdrawal`.
+    pub fn withdraw_liquidity(env: Env, provider: Address, shares: i128) -> Result<i128, Error> {
+        extend_instance(&env);
+        provider.require_auth();
+
+        if shares <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let owned = get_lp_shares(&env, &provider);
+        if shares > owned {
+            return Err(Error::InsufficientShares);
+        }
+
+        if get_open_rounds(&env) > 0 {
+            vault::queue_withdrawal(&env, &provider, shares);
+            WithdrawalQueued { provider, shares }.publish(&env);
+            return Ok(0);
+        }
+
+        set_lp_shares(&env, &provider, owned - shares);
+        vault::withdraw(&env, &provider, shares)
+    }
+
+    /// Pay out a provider's settled withdrawals
+    ///
+    /// Queued withdrawals become claimable once the last open round closes.
+    /// Returns the amount transferred.
+    pub fn claim_withdrawal(env: Env, provider: Address) -> Result<i128, Error> {
+        extend_instance(&env);
+        provider.require_auth();
+        vault::claim(&env, &provider)
+    }
+
+    /// Get price of one LP share in token units, scaled by 10^7
+    pub fn get_share_price(env: Env) -> i128 {
+        extend_instance(&env);
+        vault::share_price(&env)
+    }
+
+    /// Get LP shares held by a provider
+    pub fn get_lp_shares(env: Env, provider: Address) -> i128 {
+        extend_instance(&env);
+        get_lp_shares(&env, &provider)
+    }
+
+    /// Get LP shares a provider queued for withdrawal
+    pub fn get_pending_withdrawal(env: Env, provider: Address) -> i128 {
+        extend_instance(&env);
+        vault::pending_shares(&env, &provider)
+    }
+
+    /// Get token amount a provider can claim with `claim_withdrawal`
+    pub fn get_claimable_withdrawal(env: Env, provider: Address) -> i128 {
+        extend_instance(&env);
+        vault::claimable(&env, &provider)
+    }
+
+    /// Pay out tokens credited to a player
+    ///
+    /// Refunds of forfeited bets are credited instead of transferred, and so
+    /// are auto cash-outs that couldn't be transferred at finalization.
+    /// Returns the amount transferred.
+    pub fn claim_payout(env: Env, player: Address) -> Result<i128, Error> {
+        extend_instance(&env);
+        player.require_auth();
+
+        let amount = get_unpaid_payout(&env, &player);
+        if amount == 0 {
+            return Err(Error::NothingToClaim);
+        }
+        set_unpaid_payout(&env, &player, 0);
+        transfer(&env, &env.current_contract_address(), &player, amount)?;
+        PayoutClaimed { player, amount }.publish(&env);
+        Ok(amount)
+    }
+
+    /// Get tokens credited to a player, collected with `claim_payout`
+    pub fn get_unpaid_payout(env: Env, player: Address) -> i128 {
+        extend_instance(&env);
+        get_unpaid_payout(&env, &player)
+    }
+
+    /// Create the next round (operator only)
     /// 
-    /// Security: Server seed must match hash, multiplier pre-determined
-    pub fn start_round(
+    /// Security: Only the operator can create rounds to prevent spam
+    ///
+    /// Round ids are sequential, starting at 1. A new round can only be
+    /// created once the previous one is finalized or cancelled. While a seed
+    /// chain has unrevealed seeds, rounds must come from
+    /// `create_chained_round` so the chain's history has no gaps.
+    pub fn create_round(env: Env, server_seed_hash: BytesN<32>) -> Result<Round, Error> {
+        extend_instance(&env);
+        let operator = get_role(&env, Role::Operator);
+        operator.require_auth();
+        when_not_paused(&env)?;
+
+        if let Some(chain) = get_seed_chain(&env) {
+            if chain.revealed < chain.length {
+                return Err(Error::SeedChainActive);
+            }
+        }
+
+        open_round(&env, server_seed_hash)
+    }
+
+    /// Commit to a chain of `length` server seeds (operator only)
+    ///
+    /// Rounds created with `create_chained_round` then use the chain's links
+    /// as commitments, so no fresh hash is needed per round and the whole
+    /// history is linked. A previous chain can only be replaced once all its
+    /// seeds are revealed.
+    pub fn commit_seed_chain(
         env: Env,
-        round_id: u64,
-        server_seed: BytesN<32>,
-        crash_multiplier: u64,
+        terminal_hash: BytesN<32>,
+        length: u32,
     ) -> Result<(), Error> {
-        let admin = get_admin(&env);
-        admin.require_auth();
+        extend_instance(&env);
+        let operator = get_role(&env, Role::Operator);
+        operator.require_auth();
 
-        let mut round = get_round(&env, round_id)?;
+        if length == 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if let Some(chain) = get_seed_chain(&env) {
+            if chain.revealed < chain.length {
+                return Err(Error::SeedChainActive);
+            }
+        }
+        set_seed_chain(
+            &env,
+            &SeedChain {
+                terminal_hash: terminal_hash.clone(),
+                length,
+                head: terminal_hash.clone(),
+                revealed: 0,
+            },
+        );
+        SeedChainCommitted {
+            terminal_hash,
+            length,
+        }
+        .publish(&env);
+        Ok(())
+    }
 
-        // Verify round is in waiting state
+    /// Get committed seed chain, if any
+    pub fn get_seed_chain(env: Env) -> Option<SeedChain> {
+        extend_instance(&env);
+        get_seed_chain(&env)
+    }
+
+    /// Create the next round committed to the next link of the seed chain
+    /// (operator only)
+    ///
+    /// The round's `server_seed_hash` is the last revealed link, so
+    /// `start_round` must reveal its preimage. A round cancelled before it
+    /// starts reveals nothing and the next round reuses the same link.
+    pub fn create_chained_round(env: Env) -> Result<Round, Error> {
+        extend_instance(&env);
+        let operator = get_role(&env, Role::Operator);
+        operator.require_auth();
+        when_not_paused(&env)?;
+
+        let chain = get_seed_chain(&env).ok_or(Error::SeedChainNotFound)?;
+        if chain.revealed >= chain.length {
+            return Err(Error::SeedChainExhausted);
+        }
+        open_round(&env, chain.head)
+    }
+
+    /// Stop taking bets and open the reveal phase (operator only)
+    ///
+    /// Committed client seeds can only be revealed after this, so nobody can
+    /// pick a seed after seeing the revealed ones. The round can't start for
+    /// `min_reveal_window` seconds, giving players time to reveal. Bets still
+    /// unrevealed when the round starts are forfeited.
+    pub fn close_betting(env: Env, round_id: u64) -> Result<(), Error> {
+        extend_instance(&env);
+        let operator = get_role(&env, Role::Operator);
+        operator.require_auth();
+        when_not_paused(&env)?;
+
+        let mut round = get_round(&env, round_id)?;
         if round.status != RoundStatus::Waiting {
             return Err(Error::InvalidRoundStatus);
         }
 
+        round.status = RoundStatus::Revealing;
+        round.reveal_ends_at = env.ledger().timestamp() + get_config(&env).min_reveal_window;
+        set_round(&env, round_id, &round);
+        BettingClosed { round_id }.publish(&env);
+        Ok(())
+    }
+
+    /// Start the round with server seed (operator only)
+    /// 
+    /// Security:
+    /// - Server seed must match the committed hash
+    /// - Crash multiplier is derived on-chain from the server seed and the
+    ///   collected client seeds, so nobody can pick it
+    /// - Revealed server seed is stored for verification
+    pub fn start_round(env: Env, round_id: u64, server_seed: BytesN<32>) -> Result<(), Error> {
+        extend_instance(&env);
+        let operator = get_role(&env, Role::Operator);
+        operator.require_auth();
+        when_not_paused(&env)?;
+
+        let mut round = get_round(&env, round_id)?;
+
+        // Verify round hasn't started yet, and committed seeds had their
+        // reveal window
+        match round.status {
+            RoundStatus::Waiting if round.committed_bets > 0 => {
+                return Err(Error::RevealPhaseRequired);
+            }
+            RoundStatus::Waiting => {}
+            RoundStatus::Revealing if env.ledger().timestamp() < round.reveal_ends_at => {
+                return Err(Error::RevealWindowOpen);
+            }
+            RoundStatus::Revealing => {}
+            _ => return Err(Error::InvalidRoundStatus),
+        }
+
         // Verify server seed matches hash (CRITICAL SECURITY)
-        let server_seed_bytes: Bytes = server_seed.into();
+        let server_seed_bytes: Bytes = server_seed.clone().into();
         let calculated_hash = env.crypto().sha256(&server_seed_bytes);
         let calculated_hash_bytes: BytesN<32> = calculated_hash.into();
         if calculated_hash_bytes != round.server_seed_hash {
             return Err(Error::InvalidServerSeedHash);
         }
 
-        // Validate multiplier is reasonable (100 = 1.00x minimum)
-        if crash_multiplier < 100 {
-            return Err(Error::InvalidMultiplier);
+        // Revealing the next link moves the seed chain forward
+        if let Some(mut chain) = get_seed_chain(&env) {
+            if chain.head == round.server_seed_hash && chain.revealed < chain.length {
+                chain.head = server_seed.clone();
+                chain.revealed += 1;
+                set_seed_chain(&env, &chain);
+                set_round_chain_link(
+                    &env,
+                    round_id,
+                    &SeedChainLink {
+                        terminal_hash: chain.terminal_hash,
+                        position: chain.revealed,
+                        length: chain.length,
+                    },
+                );
+            }
+        }
+
+        // Committed seeds: mix in the revealed ones, void the rest
+        let config = get_config(&env);
+        forfeit_unrevealed_bets(&env, &mut round, config.unrevealed_penalty_bps)?;
+        round.client_seeds.append(&get_revealed_seeds(&env, round_id));
+
+        // Optionally mix in ledger data nobody knew when the seed was committed
+        let entropy = round.mix_ledger_entropy.then(|| fairness::draw_entropy(&env));
+        if let Some(entropy) = &entropy {
+            set_round_entropy(&env, round_id, entropy);
         }
 
+        // Derive crash point from server seed + client seeds (provably fair)
+        let hash = fairness::round_hash(&env, &server_seed, &round.client_seeds, entropy.as_ref());
+        let crash_multiplier =
+            fairness::crash_multiplier(&hash, round.house_edge_bps, round.max_multiplier);
+
         round.status = RoundStatus::InProgress;
+        round.server_seed = Some(server_seed.clone());
         round.crash_multiplier = crash_multiplier;
         round.started_at = env.ledger().timestamp();
 
         set_round(&env, round_id, &round);
+        RoundStarted {
+            round_id,
+            server_seed,
+            crash_multiplier,
+        }
+        .publish(&env);
         Ok(())
     }
 
     /// Place a bet in the current round
     /// 
+    /// `auto_cash_out` optionally sets a target multiplier (100 = 1.00x): if
+    /// the round reaches it, the bet is cashed out at the target without the
+    /// player sending another transaction.
+    ///
     /// Security:
     /// - Checks player balance
     /// - Validates bet amount (min/max)
+    /// - Checks the bankroll can cover the round's worst-case payout
     /// - Prevents betting after round started
     /// - Uses token transfer for XLM
     pub fn place_bet(
@@ -129,62 +568,79 @@ impl BalloonFlyContract {
         round_id: u64,
         amount: i128,
         client_seed: BytesN<32>,
+        auto_cash_out: Option<u64>,
     ) -> Result<u64, Error> {
+        extend_instance(&env);
         player.require_auth();
+        when_not_paused(&env)?;
 
-        let mut round = get_round(&env, round_id)?;
-
-        // Only allow bets in Waiting status
-        if round.status != RoundStatus::Waiting {
-            return Err(Error::InvalidRoundStatus);
-        }
-
-        // Validate bet amount (min 1 XLM = 10_000_000 stroops, max 1000 XLM)
-        if amount < 10_000_000 || amount > 1_000_000_000_000 {
-            return Err(Error::InvalidBetAmount);
-        }
-
-        // Check if player already has a bet in this round (prevent double betting)
-        let bet_id = generate_bet_id(&env, round_id, &player);
-        if env.storage().persistent().has(&bet_id) {
-            return Err(Error::BetAlreadyPlaced);
-        }
-
-        // Transfer XLM from player to contract
-        // Note: In production, use proper token contract
-        // For now, we assume payment is handled externally
+        open_bet(&env, player, round_id, amount, Some(client_seed), auto_cash_out)
+    }
 
-        // Create bet
-        let bet = Bet {
-            id: bet_id,
-            round_id,
-            player: player.clone(),
-            amount,
-            cash_out_multiplier: 0,
-            payout: 0,
-            status: BetStatus::Active,
-            timestamp: env.ledger().timestamp(),
-        };
+    /// Place a bet with a commitment to its client seed instead of the seed
+    ///
+    /// `seed_commitment` is `sha256(client_seed)`. The player reveals the
+    /// seed with `reveal_client_seed` before the round starts; every revealed
+    /// seed is mixed into the crash point. Bets still unrevealed when the
+    /// round starts are voided and refunded minus `unrevealed_penalty_bps`.
+    pub fn place_committed_bet(
+        env: Env,
+        player: Address,
+        round_id: u64,
+        amount: i128,
+        seed_commitment: BytesN<32>,
+        auto_cash_out: Option<u64>,
+    ) -> Result<u64, Error> {
+        extend_instance(&env);
+        player.require_auth();
+        when_not_paused(&env)?;
 
-        set_bet(&env, bet_id, &bet);
+        let bet_id = open_bet(&env, player, round_id, amount, None, auto_cash_out)?;
+        set_seed_commitment(&env, bet_id, &seed_commitment);
+        Ok(bet_id)
+    }
 
-        // Update round stats
-        round.total_bet_amount += amount;
-        round.bet_count += 1;
+    /// Reveal the client seed of a committed bet
+    ///
+    /// Only accepted once the operator closed betting with `close_betting`.
+    pub fn reveal_client_seed(
+        env: Env,
+        player: Address,
+        bet_id: u64,
+        client_seed: BytesN<32>,
+    ) -> Result<(), Error> {
+        extend_instance(&env);
+        player.require_auth();
 
-        // Collect client seeds from first 3 bets (for provably fair)
-        if round.client_seeds.len() < 3 {
-            round.client_seeds.push_back(client_seed);
+        let bet = get_bet(&env, bet_id)?;
+        if bet.player != player {
+            return Err(Error::Unauthorized);
+        }
+        let round = get_round(&env, bet.round_id)?;
+        if round.status != RoundStatus::Revealing {
+            return Err(Error::InvalidRoundStatus);
         }
 
-        set_round(&env, round_id, &round);
+        let commitment = get_seed_commitment(&env, bet_id).ok_or(Error::SeedNotCommitted)?;
+        let seed_bytes: Bytes = client_seed.clone().into();
+        let seed_hash: BytesN<32> = env.crypto().sha256(&seed_bytes).into();
+        if seed_hash != commitment {
+            return Err(Error::InvalidClientSeed);
+        }
 
-        // Update pool stats
-        let mut pool = get_pool(&env);
-        pool.total_bets += amount;
-        set_pool(&env, &pool);
+        remove_seed_commitment(&env, bet_id);
+        let mut revealed = get_revealed_seeds(&env, round.id);
+        revealed.push_back(client_seed.clone());
+        set_revealed_seeds(&env, round.id, &revealed);
 
-        Ok(bet_id)
+        SeedRevealed {
+            round_id: round.id,
+            player,
+            bet_id,
+            client_seed,
+        }
+        .publish(&env);
+        Ok(())
     }
 
     /// Cash out a bet at current multiplier
@@ -192,15 +648,12 @@ impl BalloonFlyContract {
     /// Security:
     /// - Verifies bet ownership
     /// - Checks bet is active
+    /// - Computes the live multiplier on-chain from the ledger timestamp
     /// - Validates multiplier hasn't crashed
     /// - Calculates payout with house edge
     /// - Prevents re-entry
-    pub fn cash_out(
-        env: Env,
-        player: Address,
-        bet_id: u64,
-        current_multiplier: u64,
-    ) -> Result<i128, Error> {
+    pub fn cash_out(env: Env, player: Address, bet_id: u64) -> Result<i128, Error> {
+        extend_instance(&env);
         player.require_auth();
 
         let mut bet = get_bet(&env, bet_id)?;
@@ -216,58 +669,93 @@ impl BalloonFlyContract {
         }
 
         let round = get_round(&env, bet.round_id)?;
+        let multiplier = cash_out_multiplier(&env, &bet, &round)?;
+        let payout = settle_cash_out(&env, &mut bet, multiplier, round.house_edge_bps);
+        transfer(&env, &env.current_contract_address(), &player, payout)?;
+        Ok(payout)
+    }
 
-        // Verify round is in progress
-        if round.status != RoundStatus::InProgress {
-            return Err(Error::InvalidRoundStatus);
+    /// Cash out part of a bet, leaving the rest of the stake riding
+    ///
+    /// `fraction_bps` is the share of the stake still riding to settle at the
+    /// live multiplier; 10000 settles the whole bet like `cash_out`. Each
+    /// partial settlement is recorded on the bet, up to
+    /// `MAX_PARTIAL_CASH_OUTS`. Returns the net payout.
+    pub fn cash_out_partial(
+        env: Env,
+        player: Address,
+        bet_id: u64,
+        fraction_bps: u32,
+    ) -> Result<i128, Error> {
+        extend_instance(&env);
+        player.require_auth();
+
+        if fraction_bps == 0 || fraction_bps > 10_000 {
+            return Err(Error::InvalidCashOutFraction);
         }
 
-        // Verify multiplier hasn't crashed yet
-        if current_multiplier >= round.crash_multiplier {
-            return Err(Error::AlreadyCrashed);
+        let mut bet = get_bet(&env, bet_id)?;
+        if bet.player != player {
+            return Err(Error::Unauthorized);
+        }
+        if bet.status != BetStatus::Active {
+            return Err(Error::BetNotActive);
         }
 
-        // Validate multiplier is reasonable (100 = 1.00x minimum)
-        if current_multiplier < 100 {
-            return Err(Error::InvalidMultiplier);
+        let round = get_round(&env, bet.round_id)?;
+        let multiplier = cash_out_multiplier(&env, &bet, &round)?;
+        if fraction_bps == 10_000 {
+            let payout = settle_cash_out(&env, &mut bet, multiplier, round.house_edge_bps);
+            transfer(&env, &env.current_contract_address(), &player, payout)?;
+            return Ok(payout);
+        }
+        if bet.partial_cash_outs.len() >= MAX_PARTIAL_CASH_OUTS {
+            return Err(Error::TooManyPartialCashOuts);
         }
 
-        // Calculate payout with house edge (3%)
-        // payout = bet_amount * (multiplier / 100) * (1 - 0.03)
-        let multiplier_factor = current_multiplier as i128;
-        let gross_payout = (bet.amount * multiplier_factor) / 100;
-        let house_fee = (gross_payout * HOUSE_EDGE_BPS as i128) / 10000;
-        let net_payout = gross_payout - house_fee;
+        let amount = riding_stake(&bet) * fraction_bps as i128 / 10_000;
+        if amount == 0 {
+            return Err(Error::InvalidCashOutFraction);
+        }
+        let payout = net_payout(amount, multiplier, round.house_edge_bps);
 
-        // Update bet status
-        bet.cash_out_multiplier = current_multiplier;
-        bet.payout = net_payout;
-        bet.status = BetStatus::CashedOut;
+        bet.partial_cash_outs.push_back(PartialCashOut {
+            amount,
+            multiplier,
+            payout,
+            timestamp: env.ledger().timestamp(),
+        });
+        bet.payout += payout;
         set_bet(&env, bet_id, &bet);
+        record_payout(&env, &bet.player, amount, multiplier, payout);
+        transfer(&env, &env.current_contract_address(), &bet.player, payout)?;
 
-        // Update pool stats
-        let mut pool = get_pool(&env);
-        pool.total_payouts += net_payout;
-        pool.total_house_earnings += house_fee;
-        set_pool(&env, &pool);
-
-        // Transfer payout to player
-        // Note: In production, use proper token contract
-        // For now, we assume payment is handled externally
+        PartialCashedOut {
+            round_id: bet.round_id,
+            player: bet.player,
+            bet_id,
+            amount,
+            multiplier,
+            payout,
+        }
+        .publish(&env);
 
-        Ok(net_payout)
+        Ok(payout)
     }
 
-    /// Finalize the round (admin only)
+    /// Finalize the round (operator only)
     /// 
     /// Security:
-    /// - Only admin can finalize
-    /// - Verifies round is in progress
-    /// - Marks all uncashed bets as lost
-    /// - Records final stats
+    /// - Only the operator can finalize
+    /// - Verifies round is in progress and the curve reached the crash point
+    /// - Cashes out bets whose auto cash-out target was reached; a payout
+    ///   that can't be transferred is credited for `claim_payout` instead
+    /// - Marks all uncashed bets as lost, keeping their partial cash-outs
+    /// - Records final stats (total payout, winners, losers, house profit)
     pub fn finalize_round(env: Env, round_id: u64) -> Result<(), Error> {
-        let admin = get_admin(&env);
-        admin.require_auth();
+        extend_instance(&env);
+        let operator = get_role(&env, Role::Operator);
+        operator.require_auth();
 
         let mut round = get_round(&env, round_id)?;
 
@@ -276,38 +764,684 @@ impl BalloonFlyContract {
             return Err(Error::InvalidRoundStatus);
         }
 
+        // Active bets can still cash out until the balloon crashes
+        if live_multiplier(&env, &round) < round.crash_multiplier {
+            return Err(Error::RoundNotCrashed);
+        }
+
+        // Settle every bet of the round
+        let mut total_payout = 0;
+        let mut winner_count = 0;
+        let mut loser_count = 0;
+        let mut lost_stakes = 0;
+        for bet_id in get_round_bets(&env, round_id).iter() {
+            let mut bet = get_bet(&env, bet_id)?;
+            match bet.status {
+                BetStatus::CashedOut => {
+                    total_payout += bet.payout;
+                    winner_count += 1;
+                }
+                BetStatus::Active => match bet.auto_cash_out {
+                    Some(target) if target <= round.crash_multiplier => {
+                        let payout = settle_cash_out(&env, &mut bet, target, round.house_edge_bps);
+                        pay_or_credit(&env, &bet.player, payout);
+                        total_payout += bet.payout;
+                        winner_count += 1;
+                    }
+                    _ => {
+                        bet.status = BetStatus::Lost;
+                        set_bet(&env, bet_id, &bet);
+                        // Partial cash-outs were paid, only the riding stake is lost
+                        total_payout += bet.payout;
+                        loser_count += 1;
+                        lost_stakes += riding_stake(&bet);
+                    }
+                },
+                BetStatus::Lost => loser_count += 1,
+                BetStatus::Refunded | BetStatus::Forfeited => {}
+            }
+        }
+
+        // Lost stakes move from escrow to the bankroll
+        let mut pool = get_pool(&env);
+        pool.bankroll += lost_stakes;
+        set_pool(&env, &pool);
+
         round.status = RoundStatus::Ended;
         round.ended_at = env.ledger().timestamp();
+        round.total_payout = total_payout;
+        round.winner_count = winner_count;
+        round.loser_count = loser_count;
+        round.house_profit = round.total_bet_amount - total_payout;
 
         set_round(&env, round_id, &round);
+        // Ended rounds are no longer bumped and expire on their own
+        extend_round_ttl(&env, round_id, ENDED_ROUND_TTL);
+        RoundFinalized {
+            round_id,
+            crash_multiplier: round.crash_multiplier,
+            total_payout,
+            winner_count,
+            loser_count,
+            house_profit: round.house_profit,
+        }
+        .publish(&env);
+
+        // Bankroll is settled, queued LP withdrawals become claimable
+        vault::round_closed(&env);
         Ok(())
     }
 
+    /// Migrate rounds and bets stored under bare `u64` ids (admin only)
+    ///
+    /// Kept for existing tooling, same as `migrate` without a token, which
+    /// also rewrites the moved entries in the current layout.
+    pub fn migrate_storage(env: Env, ids: Vec<u64>) -> Result<u32, Error> {
+        Self::migrate(env, ids, None)
+    }
+
+    /// Replace the contract code, keeping all storage (admin only)
+    ///
+    /// The new code runs from the next invocation. If it changes the storage
+    /// layout, call `migrate` right after.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        extend_instance(&env);
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+        ContractUpgraded { new_wasm_hash }.publish(&env);
+    }
+
+    /// Bring storage to the current schema version (admin only)
+    ///
+    /// Moves entries under bare `u64` ids to their typed keys, rewrites
+    /// rounds and bets stored in older layouts and upgrades the pool. Large
+    /// deployments can pass the ids in batches; every call is idempotent.
+    /// Returns how many rounds and bets were rewritten.
+    ///
+    /// Version 1 deployments only stored the admin and the pool: `token`
+    /// sets the bet token if none is stored yet (it is ignored otherwise),
+    /// and unassigned roles go to the admin, as in the constructor. Legacy
+    /// rounds must have ended and legacy bets must be settled.
+    pub fn migrate(env: Env, ids: Vec<u64>, token: Option<Address>) -> Result<u32, Error> {
+        extend_instance(&env);
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        if !has_token(&env) {
+            set_token(&env, &token.ok_or(Error::TokenNotInitialized)?);
+        }
+        for role in [Role::Operator, Role::Treasurer, Role::Pauser] {
+            if !has_role(&env, role) {
+                set_role(&env, role, &admin);
+            }
+        }
+        upgrade_config_layout(&env);
+        let config = get_config(&env);
+        upgrade_pool_layout(&env);
+        upgrade_withdrawal_queue(&env);
+
+        let mut migrated = 0;
+        for id in ids.iter() {
+            let moved = migrate_legacy_entry(&env, id)?;
+            let rewritten =
+                upgrade_round_layout(&env, id, &config) | upgrade_bet_layout(&env, id);
+            if moved || rewritten {
+                migrated += 1;
+            }
+            // Keep new round ids clear of migrated ones
+            if has_round(&env, id) && id > get_last_round_id(&env) {
+                set_last_round_id(&env, id);
+            }
+        }
+
+        let from_version = get_version(&env);
+        if from_version != SCHEMA_VERSION {
+            set_version(&env, SCHEMA_VERSION);
+            StorageMigrated {
+                from_version,
+                to_version: SCHEMA_VERSION,
+            }
+            .publish(&env);
+        }
+        Ok(migrated)
+    }
+
+    /// Get storage schema version
+    pub fn version(env: Env) -> u32 {
+        extend_instance(&env);
+        get_version(&env)
+    }
+
+    /// Extend the TTL of a round and its bets (anyone can call)
+    ///
+    /// Keepers call this for rounds that are still open or have refunds
+    /// to claim. Ended rounds get a fixed TTL when finalized and can't be
+    /// bumped, so they expire on purpose.
+    pub fn bump_round(env: Env, round_id: u64) -> Result<(), Error> {
+        extend_instance(&env);
+        let round = get_round(&env, round_id)?;
+        if round.status == RoundStatus::Ended {
+            return Err(Error::InvalidRoundStatus);
+        }
+        extend_round_ttl(&env, round_id, PERSISTENT_BUMP_AMOUNT);
+        Ok(())
+    }
+
+    /// Get the latest round if it is still waiting or in progress
+    pub fn current_round(env: Env) -> Option<Round> {
+        extend_instance(&env);
+        let round = get_round(&env, get_last_round_id(&env)).ok()?;
+        match round.status {
+            RoundStatus::Waiting | RoundStatus::Revealing | RoundStatus::InProgress => Some(round),
+            RoundStatus::Ended | RoundStatus::Cancelled => None,
+        }
+    }
+
     /// Get round details
     pub fn get_round(env: Env, round_id: u64) -> Result<Round, Error> {
+        extend_instance(&env);
         get_round(&env, round_id)
     }
 
+    /// Cancel a round that hasn't started (operator only)
+    ///
+    /// Every active bet of a cancelled round can be refunded with `claim_refund`.
+    /// The operator knows the server seed, so once client seeds are collected
+    /// they can compute the crash point and would only cancel rounds they'd
+    /// lose. Cancelling is therefore limited to rounds without client seeds,
+    /// or rounds whose crash point also mixes in ledger entropy drawn at
+    /// start. Other rounds, and rounds in progress, can only be cancelled
+    /// through the timeouts of `claim_refund`.
+    pub fn cancel_round(env: Env, round_id: u64) -> Result<(), Error> {
+        extend_instance(&env);
+        let operator = get_role(&env, Role::Operator);
+        operator.require_auth();
+
+        let mut round = get_round(&env, round_id)?;
+
+        if round.status != RoundStatus::Waiting && round.status != RoundStatus::Revealing {
+            return Err(Error::InvalidRoundStatus);
+        }
+        let seeds_collected =
+            !round.client_seeds.is_empty() || !get_revealed_seeds(&env, round_id).is_empty();
+        if seeds_collected && !round.mix_ledger_entropy {
+            return Err(Error::ClientSeedsCollected);
+        }
+
+        round.status = RoundStatus::Cancelled;
+        round.ended_at = env.ledger().timestamp();
+
+        set_round(&env, round_id, &round);
+        RoundCancelled { round_id }.publish(&env);
+        vault::round_closed(&env);
+        Ok(())
+    }
+
+    /// Refund the stake of an active bet (anyone can call)
+    ///
+    /// Available once the round is cancelled, or when it is stuck: not started
+    /// `waiting_timeout` seconds after creation or not finalized
+    /// `in_progress_timeout` seconds after start, as configured when the round
+    /// was created. A stuck round is cancelled by the first refund so it can't
+    /// be resumed afterwards. The stake always goes back to the bet's player,
+    /// minus any part already settled by partial cash-outs; returns the
+    /// refunded amount.
+    pub fn claim_refund(env: Env, bet_id: u64) -> Result<i128, Error> {
+        extend_instance(&env);
+        let mut bet = get_bet(&env, bet_id)?;
+
+        if bet.status != BetStatus::Active {
+            return Err(Error::BetNotActive);
+        }
+
+        let mut round = get_round(&env, bet.round_id)?;
+        let now = env.ledger().timestamp();
+
+        // Timeouts come from the round, a config change can't move them
+        let timed_out = match round.status {
+            RoundStatus::Waiting | RoundStatus::Revealing => {
+                now >= round.created_at + round.waiting_timeout
+            }
+            RoundStatus::InProgress => now >= round.started_at + round.in_progress_timeout,
+            _ => false,
+        };
+        if timed_out {
+            round.status = RoundStatus::Cancelled;
+            round.ended_at = now;
+            set_round(&env, round.id, &round);
+            RoundCancelled { round_id: round.id }.publish(&env);
+            vault::round_closed(&env);
+        }
+
+        if round.status != RoundStatus::Cancelled {
+            return Err(Error::RefundNotAvailable);
+        }
+
+        bet.status = BetStatus::Refunded;
+        set_bet(&env, bet_id, &bet);
+        remove_seed_commitment(&env, bet_id);
+
+        // Refunded stakes were never really wagered, partial cash-outs stay settled
+        let amount = riding_stake(&bet);
+        let mut pool = get_pool(&env);
+        pool.total_bets -= amount;
+        set_pool(&env, &pool);
+        stats::record_refund(&env, &bet.player, bet.round_id, amount);
+
+        transfer(&env, &env.current_contract_address(), &bet.player, amount)?;
+
+        RefundClaimed {
+            round_id: bet.round_id,
+            player: bet.player,
+            bet_id,
+            amount,
+        }
+        .publish(&env);
+
+        Ok(amount)
+    }
+
+    /// Verify a round's provably fair outcome
+    ///
+    /// Recomputes the seed hash and the crash multiplier from the stored seeds
+    /// so verifiers don't have to trust the frontend.
+    pub fn verify_round(env: Env, round_id: u64) -> Result<RoundVerification, Error> {
+        extend_instance(&env);
+        let round = get_round(&env, round_id)?;
+
+        // Server seed is only known once the round started
+        let server_seed = round.server_seed.ok_or(Error::InvalidRoundStatus)?;
+
+        let server_seed_bytes: Bytes = server_seed.clone().into();
+        let calculated_hash: BytesN<32> = env.crypto().sha256(&server_seed_bytes).into();
+        let seed_hash_valid = calculated_hash == round.server_seed_hash;
+
+        let entropy = get_round_entropy(&env, round_id);
+        let round_hash =
+            fairness::round_hash(&env, &server_seed, &round.client_seeds, entropy.as_ref());
+        let (entropy, formula) = match entropy {
+            Some(entropy) => (
+                EntropySource::Ledger(entropy),
+                fairness::ROUND_HASH_WITH_ENTROPY_FORMULA,
+            ),
+            None => (EntropySource::None, fairness::ROUND_HASH_FORMULA),
+        };
+        let seed_chain = match get_round_chain_link(&env, round_id) {
+            Some(link) => ChainSource::Link(link),
+            None => ChainSource::None,
+        };
+        let crash_multiplier =
+            fairness::crash_multiplier(&round_hash, round.house_edge_bps, round.max_multiplier);
+        let crash_multiplier_valid = crash_multiplier == round.crash_multiplier;
+
+        Ok(RoundVerification {
+            round_id,
+            server_seed_hash: round.server_seed_hash,
+            server_seed,
+            seed_chain,
+            client_seeds: round.client_seeds,
+            entropy,
+            formula: String::from_str(&env, formula),
+            round_hash,
+            crash_multiplier,
+            recorded_crash_multiplier: round.crash_multiplier,
+            seed_hash_valid,
+            crash_multiplier_valid,
+            verified: seed_hash_valid && crash_multiplier_valid,
+        })
+    }
+
+    /// Get the live multiplier of a round in progress (100 = 1.00x)
+    ///
+    /// Follows the published growth curve and stops at the crash point.
+    pub fn get_multiplier(env: Env, round_id: u64) -> Result<u64, Error> {
+        extend_instance(&env);
+        let round = get_round(&env, round_id)?;
+        if round.status != RoundStatus::InProgress {
+            return Err(Error::InvalidRoundStatus);
+        }
+        Ok(live_multiplier(&env, &round).min(round.crash_multiplier))
+    }
+
     /// Get bet details
     pub fn get_bet(env: Env, bet_id: u64) -> Result<Bet, Error> {
+        extend_instance(&env);
         get_bet(&env, bet_id)
     }
 
+    /// Get a page of the bets placed in a round, in placement order
+    ///
+    /// `limit` is capped at `MAX_PAGE_SIZE`.
+    pub fn get_round_bets(
+        env: Env,
+        round_id: u64,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<Vec<Bet>, Error> {
+        extend_instance(&env);
+        if !has_round(&env, round_id) {
+            return Err(Error::RoundNotFound);
+        }
+
+        let bet_ids = get_round_bets(&env, round_id);
+        let end = bet_ids.len().min(cursor.saturating_add(limit.min(MAX_PAGE_SIZE)));
+        let mut bets = Vec::new(&env);
+        for i in cursor..end {
+            bets.push_back(get_bet(&env, bet_ids.get_unchecked(i))?);
+        }
+        Ok(bets)
+    }
+
+    /// Get the bets cashed out in a round, highest payout first
+    ///
+    /// Bets with the same payout keep the order they were placed in.
+    pub fn get_round_cashouts(env: Env, round_id: u64) -> Result<Vec<Bet>, Error> {
+        extend_instance(&env);
+        if !has_round(&env, round_id) {
+            return Err(Error::RoundNotFound);
+        }
+
+        let mut cashouts: Vec<Bet> = Vec::new(&env);
+        for bet_id in get_round_bets(&env, round_id).iter() {
+            let bet = get_bet(&env, bet_id)?;
+            if bet.status != BetStatus::CashedOut {
+                continue;
+            }
+            // Insertion sort, after every bet paying at least as much
+            let mut position = cashouts.len();
+            while position > 0 && cashouts.get_unchecked(position - 1).payout < bet.payout {
+                position -= 1;
+            }
+            cashouts.insert(position, bet);
+        }
+        Ok(cashouts)
+    }
+
+    /// Get the bets a player placed in a round, in placement order
+    pub fn get_player_round_bets(env: Env, player: Address, round_id: u64) -> Vec<Bet> {
+        extend_instance(&env);
+        let mut bets = Vec::new(&env);
+        for bet_id in get_player_round_bets(&env, &player, round_id).iter() {
+            if let Ok(bet) = get_bet(&env, bet_id) {
+                bets.push_back(bet);
+            }
+        }
+        bets
+    }
+
+    /// Get a page of a player's bets, oldest first
+    ///
+    /// `cursor` is the position in the player's history to start from and
+    /// `limit` is capped at `MAX_PAGE_SIZE`. Use `get_player_bet_count` to
+    /// page from the most recent bets backwards.
+    pub fn get_player_bets(env: Env, player: Address, cursor: u32, limit: u32) -> Vec<Bet> {
+        extend_instance(&env);
+        let count = get_player_bet_count(&env, &player);
+        let end = count.min(cursor.saturating_add(limit.min(MAX_PAGE_SIZE)));
+
+        let mut bets = Vec::new(&env);
+        let mut chunk = Vec::new(&env);
+        for i in cursor..end {
+            if i == cursor || i % PLAYER_BETS_CHUNK_SIZE == 0 {
+                chunk = get_player_bet_chunk(&env, &player, i / PLAYER_BETS_CHUNK_SIZE);
+            }
+            let bet_id = chunk.get_unchecked(i % PLAYER_BETS_CHUNK_SIZE);
+            if let Ok(bet) = get_bet(&env, bet_id) {
+                bets.push_back(bet);
+            }
+        }
+        bets
+    }
+
+    /// Get number of bets a player placed
+    pub fn get_player_bet_count(env: Env, player: Address) -> u32 {
+        extend_instance(&env);
+        get_player_bet_count(&env, &player)
+    }
+
+    /// Get lifetime stats of a player
+    pub fn get_player_stats(env: Env, player: Address) -> PlayerStats {
+        extend_instance(&env);
+        get_player_stats(&env, &player)
+    }
+
     /// Get pool statistics
     pub fn get_pool(env: Env) -> Pool {
+        extend_instance(&env);
         get_pool(&env)
     }
 }
 
-/// Generate unique bet ID from round ID and player address
-fn generate_bet_id(env: &Env, round_id: u64, player: &Address) -> u64 {
-    // Combine round_id and player address into bytes
+/// Create the next round, committed to `server_seed_hash`
+fn open_round(env: &Env, server_seed_hash: BytesN<32>) -> Result<Round, Error> {
+    if get_open_rounds(env) > 0 {
+        return Err(Error::PreviousRoundOpen);
+    }
+
+    // Prevent round ID reuse (security)
+    let round_id = get_last_round_id(env) + 1;
+    if has_round(env, round_id) {
+        return Err(Error::RoundAlreadyExists);
+    }
+    set_last_round_id(env, round_id);
+
+    let config = get_config(env);
+    let round = Round {
+        id: round_id,
+        status: RoundStatus::Waiting,
+        server_seed_hash,
+        server_seed: None,
+        crash_multiplier: 0, // Will be set when round starts
+        house_edge_bps: config.house_edge_bps,
+        max_multiplier: config.max_multiplier,
+        mix_ledger_entropy: config.mix_ledger_entropy,
+        waiting_timeout: config.waiting_timeout,
+        in_progress_timeout: config.in_progress_timeout,
+        created_at: env.ledger().timestamp(),
+        started_at: 0,
+        ended_at: 0,
+        total_bet_amount: 0,
+        total_payout: 0,
+        bet_count: 0,
+        client_seeds: Vec::new(env),
+        winner_count: 0,
+        loser_count: 0,
+        house_profit: 0,
+        committed_bets: 0,
+        reveal_ends_at: 0,
+    };
+
+    set_round(env, round_id, &round);
+    vault::round_opened(env);
+    RoundCreated {
+        round_id,
+        server_seed_hash: round.server_seed_hash.clone(),
+    }
+    .publish(env);
+    Ok(round)
+}
+
+/// Escrow a stake and record a new bet in a round
+fn open_bet(
+    env: &Env,
+    player: Address,
+    round_id: u64,
+    amount: i128,
+    client_seed: Option<BytesN<32>>,
+    auto_cash_out: Option<u64>,
+) -> Result<u64, Error> {
+    let mut round = get_round(env, round_id)?;
+
+    // Only allow bets in Waiting status, before seed reveals start
+    if round.status == RoundStatus::Revealing {
+        return Err(Error::BettingClosed);
+    }
+    if round.status != RoundStatus::Waiting {
+        return Err(Error::InvalidRoundStatus);
+    }
+
+    // Validate bet amount against configured bounds
+    let config = get_config(env);
+    if !(config.min_bet..=config.max_bet).contains(&amount) {
+        return Err(Error::InvalidBetAmount);
+    }
+
+    // Worst case every stake of the round rides to the max multiplier
+    let pool = get_pool(env);
+    let worst_case_payout = net_payout(
+        round.total_bet_amount + amount,
+        round.max_multiplier,
+        round.house_edge_bps,
+    );
+    if worst_case_payout > pool.bankroll * config.max_exposure_bps as i128 / 10_000 {
+        return Err(Error::InsufficientBankroll);
+    }
+
+    // Auto cash-out target must be above 1.00x
+    if let Some(target) = auto_cash_out {
+        if target <= 100 {
+            return Err(Error::InvalidMultiplier);
+        }
+    }
+
+    // Cap the bets of a round so it can be settled in one transaction
+    if round.bet_count >= config.max_bets_per_round {
+        return Err(Error::RoundFull);
+    }
+
+    // Cap the bets a player can place in one round
+    if get_player_round_bets(env, &player, round_id).len() >= config.max_bets_per_player {
+        return Err(Error::BetAlreadyPlaced);
+    }
+    let bet_id = generate_bet_id(env, round_id, round.bet_count);
+    if has_bet(env, bet_id) {
+        return Err(Error::BetAlreadyPlaced);
+    }
+
+    // Transfer stake from player to contract
+    transfer(env, &player, &env.current_contract_address(), amount)?;
+
+    // Create bet
+    let bet = Bet {
+        id: bet_id,
+        round_id,
+        player: player.clone(),
+        amount,
+        cash_out_multiplier: 0,
+        payout: 0,
+        status: BetStatus::Active,
+        timestamp: env.ledger().timestamp(),
+        auto_cash_out,
+        partial_cash_outs: Vec::new(env),
+    };
+
+    set_bet(env, bet_id, &bet);
+    stats::record_bet(env, &player, round_id, amount);
+    add_round_bet(env, round_id, bet_id);
+    add_player_bet(env, &player, bet_id);
+    add_player_round_bet(env, &player, round_id, bet_id);
+
+    // Update round stats
+    round.total_bet_amount += amount;
+    round.bet_count += 1;
+
+    // Collect client seeds from first bets (for provably fair), the other
+    // bets commit to theirs and reveal it once betting closes
+    match client_seed {
+        Some(client_seed) => {
+            if round.client_seeds.len() < config.max_client_seeds {
+                round.client_seeds.push_back(client_seed);
+            }
+        }
+        None => round.committed_bets += 1,
+    }
+
+    set_round(env, round_id, &round);
+
+    // Update pool stats
+    let mut pool = get_pool(env);
+    pool.total_bets += amount;
+    set_pool(env, &pool);
+
+    BetPlaced {
+        round_id,
+        player,
+        bet_id,
+        amount,
+        auto_cash_out,
+    }
+    .publish(env);
+
+    Ok(bet_id)
+}
+
+/// Void bets whose committed seed wasn't revealed before the round started
+///
+/// The stake minus the penalty is credited to the player, who collects it
+/// with `claim_payout`; the penalty goes to the bankroll. Nothing is
+/// transferred, so a player that can't receive tokens can't keep the round
+/// from starting.
+fn forfeit_unrevealed_bets(env: &Env, round: &mut Round, penalty_bps: u32) -> Result<(), Error> {
+    for bet_id in get_round_bets(env, round.id).iter() {
+        if get_seed_commitment(env, bet_id).is_none() {
+            continue;
+        }
+        remove_seed_commitment(env, bet_id);
+
+        let mut bet = get_bet(env, bet_id)?;
+        let penalty = bet.amount * penalty_bps as i128 / 10_000;
+        let refund = bet.amount - penalty;
+        bet.status = BetStatus::Forfeited;
+        bet.payout = refund;
+        set_bet(env, bet_id, &bet);
+        round.total_bet_amount -= bet.amount;
+
+        let mut pool = get_pool(env);
+        pool.total_bets -= bet.amount;
+        pool.total_house_earnings += penalty;
+        pool.bankroll += penalty;
+        set_pool(env, &pool);
+        stats::record_forfeit(env, &bet.player, bet.round_id, bet.amount, penalty);
+
+        credit_payout(env, &bet.player, refund);
+        BetForfeited {
+            round_id: round.id,
+            player: bet.player,
+            bet_id,
+            refund,
+            penalty,
+        }
+        .publish(env);
+    }
+    Ok(())
+}
+
+/// Add tokens a player can collect with `claim_payout`
+fn credit_payout(env: &Env, player: &Address, amount: i128) {
+    set_unpaid_payout(env, player, get_unpaid_payout(env, player) + amount);
+}
+
+/// Fail with `ContractPaused` while the contract is paused
+///
+/// Mirrors `when_not_paused` from the workspace's `stellar-pausable`. The
+/// workspace pins it at v0.3.0, which is built on soroban-sdk 22 and can't
+/// share an `Env` with this contract's soroban-sdk 23; later releases ship
+/// pausing in `stellar-contract-utils`, which the workspace doesn't pin.
+fn when_not_paused(env: &Env) -> Result<(), Error> {
+    if is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+    Ok(())
+}
+
+/// Generate unique bet ID from round ID and the bet's nonce in the round
+fn generate_bet_id(env: &Env, round_id: u64, nonce: u32) -> u64 {
+    // Combine round_id and nonce into bytes
     let mut data = Bytes::new(env);
     data.append(&Bytes::from_array(env, &round_id.to_be_bytes()));
-    
-    // Convert player address to bytes
-    let player_bytes = player.to_string().to_bytes();
-    data.append(&player_bytes);
+    data.append(&Bytes::from_array(env, &nonce.to_be_bytes()));
     
     let hash = env.crypto().sha256(&data);
     let hash_bytes: BytesN<32> = hash.into();
@@ -320,6 +1454,109 @@ fn generate_bet_id(env: &Env, round_id: u64, player: &Address) -> u64 {
     id
 }
 
+/// Payout for an amount at a multiplier, net of the house edge
+///
+/// payout = amount * (multiplier / 100) * (1 - house_edge)
+fn net_payout(amount: i128, multiplier: u64, house_edge_bps: u32) -> i128 {
+    let gross_payout = (amount * multiplier as i128) / 100;
+    gross_payout - (gross_payout * house_edge_bps as i128) / 10000
+}
+
+/// Part of a bet's stake not secured by partial cash-outs yet
+fn riding_stake(bet: &Bet) -> i128 {
+    bet.partial_cash_outs
+        .iter()
+        .fold(bet.amount, |stake, partial| stake - partial.amount)
+}
+
+/// Multiplier an active bet cashes out at right now
+fn cash_out_multiplier(env: &Env, bet: &Bet, round: &Round) -> Result<u64, Error> {
+    // Verify round is in progress
+    if round.status != RoundStatus::InProgress {
+        return Err(Error::InvalidRoundStatus);
+    }
+
+    let current_multiplier = live_multiplier(env, round);
+    match bet.auto_cash_out {
+        // Auto cash-out target already reached: the bet left at the target
+        Some(target) if target <= current_multiplier && target <= round.crash_multiplier => {
+            Ok(target)
+        }
+        _ => {
+            // Verify multiplier hasn't crashed yet
+            if current_multiplier >= round.crash_multiplier {
+                return Err(Error::AlreadyCrashed);
+            }
+            Ok(current_multiplier)
+        }
+    }
+}
+
+/// Cash out the riding stake of a bet at the given multiplier
+///
+/// Returns the net payout, which the caller sends to the player.
+fn settle_cash_out(env: &Env, bet: &mut Bet, multiplier: u64, house_edge_bps: u32) -> i128 {
+    let stake = riding_stake(bet);
+    let net_payout = net_payout(stake, multiplier, house_edge_bps);
+
+    // Update bet status
+    bet.cash_out_multiplier = multiplier;
+    bet.payout += net_payout;
+    bet.status = BetStatus::CashedOut;
+    set_bet(env, bet.id, bet);
+    record_payout(env, &bet.player, stake, multiplier, net_payout);
+
+    CashedOut {
+        round_id: bet.round_id,
+        player: bet.player.clone(),
+        bet_id: bet.id,
+        multiplier,
+        payout: net_payout,
+    }
+    .publish(env);
+
+    net_payout
+}
+
+/// Record a cashed out stake in the pool and player stats
+fn record_payout(env: &Env, player: &Address, stake: i128, multiplier: u64, net_payout: i128) {
+    let house_fee = (stake * multiplier as i128) / 100 - net_payout;
+    stats::record_cash_out(env, player, multiplier, net_payout);
+
+    // Update pool stats
+    let mut pool = get_pool(env);
+    pool.total_payouts += net_payout;
+    pool.total_house_earnings += house_fee;
+    // Stake comes out of escrow, the bankroll covers the rest
+    pool.bankroll -= net_payout - stake;
+    set_pool(env, &pool);
+}
+
+/// Transfer a payout, or credit it for `claim_payout` if the transfer fails
+///
+/// Used where the player isn't the caller, so one recipient that can't
+/// receive tokens doesn't roll back everyone else's settlement.
+fn pay_or_credit(env: &Env, player: &Address, amount: i128) {
+    if transfer(env, &env.current_contract_address(), player, amount).is_err() {
+        credit_payout(env, player, amount);
+    }
+}
+
+/// Multiplier reached by the growth curve at the current ledger timestamp
+fn live_multiplier(env: &Env, round: &Round) -> u64 {
+    let elapsed = env.ledger().timestamp().saturating_sub(round.started_at);
+    curve::multiplier_at(elapsed)
+}
+
+/// Transfer tokens using the configured token contract
+fn transfer(env: &Env, from: &Address, to: &Address, amount: i128) -> Result<(), Error> {
+    let client = token::Client::new(env, &get_token(env));
+    match client.try_transfer(from, to, &amount) {
+        Ok(Ok(())) => Ok(()),
+        _ => Err(Error::TransferFailed),
+    }
+}
+
 #[cfg(test)]
 mod test;
 
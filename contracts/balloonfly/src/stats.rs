@@ -0,0 +1,65 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    storage::{get_bet, get_player_round_bets, get_player_stats, set_player_stats},
+    types::BetStatus,
+};
+
+/// Record a placed bet in the player's stats
+///
+/// Must run before the bet is added to the player's bets of the round, so
+/// several bets in one round count as a single round played.
+pub fn record_bet(env: &Env, player: &Address, round_id: u64, amount: i128) {
+    let mut stats = get_player_stats(env, player);
+    stats.total_wagered += amount;
+    stats.net_pnl -= amount;
+    if get_player_round_bets(env, player, round_id).is_empty() {
+        stats.rounds_played += 1;
+    }
+    stats.last_played = env.ledger().timestamp();
+    set_player_stats(env, player, &stats);
+}
+
+/// Record a cash-out, manual or automatic, in the player's stats
+pub fn record_cash_out(env: &Env, player: &Address, multiplier: u64, payout: i128) {
+    let mut stats = get_player_stats(env, player);
+    stats.total_won += payout;
+    stats.net_pnl += payout;
+    stats.biggest_multiplier = stats.biggest_multiplier.max(multiplier);
+    stats.biggest_payout = stats.biggest_payout.max(payout);
+    set_player_stats(env, player, &stats);
+}
+
+/// Undo a forfeited bet, keeping the penalty as a loss
+///
+/// Must run after the bet is marked forfeited.
+pub fn record_forfeit(env: &Env, player: &Address, round_id: u64, amount: i128, penalty: i128) {
+    let mut stats = get_player_stats(env, player);
+    stats.total_wagered -= amount;
+    stats.net_pnl += amount - penalty;
+    if !plays_round(env, player, round_id) {
+        stats.rounds_played -= 1;
+    }
+    set_player_stats(env, player, &stats);
+}
+
+/// Undo a refunded bet, as if it was never placed
+///
+/// Must run after the bet is marked refunded.
+pub fn record_refund(env: &Env, player: &Address, round_id: u64, amount: i128) {
+    let mut stats = get_player_stats(env, player);
+    stats.total_wagered -= amount;
+    stats.net_pnl += amount;
+    if !plays_round(env, player, round_id) {
+        stats.rounds_played -= 1;
+    }
+    set_player_stats(env, player, &stats);
+}
+
+/// Whether the player still has a bet in the round that wasn't undone
+fn plays_round(env: &Env, player: &Address, round_id: u64) -> bool {
+    get_player_round_bets(env, player, round_id).iter().any(|bet_id| {
+        get_bet(env, bet_id)
+            .is_ok_and(|bet| !matches!(bet.status, BetStatus::Refunded | BetStatus::Forfeited))
+    })
+}
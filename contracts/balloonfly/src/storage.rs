@@ -1,13 +1,145 @@
-use soroban_sdk::{symbol_short, Address, Env, Symbol};
+use soroban_sdk::{
+    contracttype, symbol_short, Address, BytesN, Env, IntoVal, Map, Symbol, TryFromVal, Val, Vec,
+};
 
-use crate::{error::Error, types::{Bet, Pool, Round}};
+use crate::{
+    error::Error,
+    types::{
+        Bet, BetStatus, BetV1, BetV2, Config, PlayerStats, Pool, PoolV1, QueuedWithdrawal, Role, Round,
+        RoundEntropy, RoundStatus, RoundV1, RoundV2, RoundV3, RoundV4, SeedChain, SeedChainLink,
+        SettledEpoch,
+    },
+};
 
-// Storage keys
+/// Keys for persistent storage (and keyed instance entries such as roles)
+///
+/// Rounds and bets used to be stored under their bare `u64` id, which put
+/// both in the same keyspace. Tagging each id with its kind keeps them apart.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Round(u64),
+    Bet(u64),
+    RoundBets(u64), // Bet ids placed in a round, in order
+    LpShares(Address), // LP shares held by a provider
+    PendingWithdrawal(Address), // LP shares queued for withdrawal and their epoch
+    ClaimableWithdrawal(Address), // Settled withdrawal waiting to be claimed
+    Role(Role), // Address holding a role (instance storage)
+    PlayerBetCount(Address), // Number of bets a player placed
+    PlayerBets(Address, u32), // Chunk of a player's bet ids, oldest first
+    PlayerStats(Address), // Lifetime stats of a player
+    SeedCommitment(u64), // sha256 of a bet's client seed, until revealed
+    RevealedSeeds(u64), // Client seeds revealed in a round, in reveal order
+    RoundEntropy(u64), // Ledger entropy mixed into a round's crash point
+    PlayerRoundBets(Address, u64), // Bet ids a player placed in a round
+    WithdrawalEpoch(u32), // Settled share price of a withdrawal epoch
+    UnpaidPayout(Address), // Tokens credited to a player, collected with `claim_payout`
+    RoundChainLink(u64), // Seed chain position of a round's server seed
+}
+
+// Storage keys (instance)
 pub const ADMIN: Symbol = symbol_short!("ADMIN");
+pub const PENDING_ADMIN: Symbol = symbol_short!("PEND_ADM");
 pub const POOL: Symbol = symbol_short!("POOL");
+pub const TOKEN: Symbol = symbol_short!("TOKEN");
+pub const CONFIG: Symbol = symbol_short!("CONFIG");
+pub const OPEN_ROUNDS: Symbol = symbol_short!("OPEN_RNDS");
+pub const WITHDRAWALS: Symbol = symbol_short!("WITHDRAWS"); // Queue of schema 8 and older
+pub const WITHDRAWAL_EPOCH: Symbol = symbol_short!("WD_EPOCH");
+pub const QUEUED_SHARES: Symbol = symbol_short!("WD_QUEUED");
+pub const PAUSED: Symbol = symbol_short!("PAUSED");
+pub const VERSION: Symbol = symbol_short!("VERSION");
+pub const LAST_ROUND: Symbol = symbol_short!("LAST_RND");
+pub const SEED_CHAIN: Symbol = symbol_short!("SEED_CHN");
+
+/// Storage schema version written by this code
+///
+/// 1: original layout, rounds and bets under bare `u64` ids
+/// 2: typed `DataKey`s, seeds and config snapshots on rounds, auto cash-out
+///    on bets, bankroll and LP shares in the pool
+/// 3: unrevealed seed penalty in the config
+/// 4: ledger entropy switch in the config
+/// 5: bets per player per round cap in the config, bet ids keyed by a
+///    per-round nonce
+/// 6: partial cash-outs on bets
+/// 7: bets per round cap in the config
+/// 8: ledger entropy switch stored on rounds
+/// 9: withdrawals queued per epoch instead of in an instance queue
+/// 10: committed bets and reveal deadline on rounds, reveal window in the
+///     config
+/// 11: refund timeouts stored on rounds
+pub const SCHEMA_VERSION: u32 = 11;
+
+// Player bet index
+pub const PLAYER_BETS_CHUNK_SIZE: u32 = 100; // Bet ids per index entry
+pub const MAX_PAGE_SIZE: u32 = 50; // Max records returned by paginated views
+
+// Partial cash-outs
+pub const MAX_PARTIAL_CASH_OUTS: u32 = 10; // Partial settlements kept on a bet
+
+// Round size
+pub const MAX_BETS_PER_ROUND: u32 = 15; // Settling a bet writes up to 3 ledger entries, a transaction up to 50
+
+// TTL policy, in ledgers (~5 seconds each)
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+pub const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub const ENDED_ROUND_TTL: u32 = 30 * DAY_IN_LEDGERS; // Ended rounds expire this long after finalization
 
-// Constants
-pub const HOUSE_EDGE_BPS: u32 = 300; // 3% = 300 basis points
+// Default configuration
+pub const DEFAULT_HOUSE_EDGE_BPS: u32 = 300; // 3% = 300 basis points
+pub const DEFAULT_MIN_BET: i128 = 10_000_000; // 1 XLM
+pub const DEFAULT_MAX_BET: i128 = 1_000_000_000_000; // 100,000 XLM
+pub const DEFAULT_MAX_MULTIPLIER: u64 = 10_000; // 100.00x
+pub const DEFAULT_MAX_CLIENT_SEEDS: u32 = 3;
+pub const DEFAULT_WAITING_TIMEOUT: u64 = 3_600; // Round not started 1h after creation
+pub const DEFAULT_IN_PROGRESS_TIMEOUT: u64 = 3_600; // Round not finalized 1h after start
+pub const DEFAULT_MAX_EXPOSURE_BPS: u32 = 1_000; // 10% of the bankroll
+pub const DEFAULT_UNREVEALED_PENALTY_BPS: u32 = 1_000; // 10% of the stake
+pub const DEFAULT_MIX_LEDGER_ENTROPY: bool = false;
+pub const DEFAULT_MAX_BETS_PER_PLAYER: u32 = 1;
+pub const DEFAULT_MAX_BETS_PER_ROUND: u32 = MAX_BETS_PER_ROUND;
+pub const DEFAULT_MIN_REVEAL_WINDOW: u64 = 60; // 1 minute to reveal committed seeds
+
+/// Extend instance storage (config, pool, roles) and the contract code
+pub fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+/// Extend a persistent entry to the standard TTL
+fn extend_persistent(env: &Env, key: &DataKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+/// Extend a round, its bet index and all of its bets to `extend_to` ledgers
+pub fn extend_round_ttl(env: &Env, round_id: u64, extend_to: u32) {
+    let storage = env.storage().persistent();
+    storage.extend_ttl(&DataKey::Round(round_id), extend_to, extend_to);
+
+    let entropy = DataKey::RoundEntropy(round_id);
+    if storage.has(&entropy) {
+        storage.extend_ttl(&entropy, extend_to, extend_to);
+    }
+
+    let link = DataKey::RoundChainLink(round_id);
+    if storage.has(&link) {
+        storage.extend_ttl(&link, extend_to, extend_to);
+    }
+
+    let index = DataKey::RoundBets(round_id);
+    if storage.has(&index) {
+        storage.extend_ttl(&index, extend_to, extend_to);
+        for bet_id in get_round_bets(env, round_id).iter() {
+            storage.extend_ttl(&DataKey::Bet(bet_id), extend_to, extend_to);
+        }
+    }
+}
 
 /// Check if admin is initialized
 pub fn has_admin(env: &Env) -> bool {
@@ -27,30 +159,757 @@ pub fn set_admin(env: &Env, admin: &Address) {
     env.storage().instance().set(&ADMIN, admin);
 }
 
+/// Get admin address proposed by the current admin
+pub fn get_pending_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&PENDING_ADMIN)
+}
+
+/// Set proposed admin address
+pub fn set_pending_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&PENDING_ADMIN, admin);
+}
+
+/// Clear proposed admin address
+pub fn remove_pending_admin(env: &Env) {
+    env.storage().instance().remove(&PENDING_ADMIN);
+}
+
+/// Get address holding a role
+pub fn get_role(env: &Env, role: Role) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::Role(role))
+        .expect("Role not initialized")
+}
+
+/// Check if a role has been assigned
+pub fn has_role(env: &Env, role: Role) -> bool {
+    env.storage().instance().has(&DataKey::Role(role))
+}
+
+/// Set address holding a role
+pub fn set_role(env: &Env, role: Role, account: &Address) {
+    env.storage().instance().set(&DataKey::Role(role), account);
+}
+
+/// Check if the contract is paused
+pub fn is_paused(env: &Env) -> bool {
+    env.storage().instance().get(&PAUSED).unwrap_or(false)
+}
+
+/// Set paused flag
+pub fn set_paused(env: &Env, paused: bool) {
+    env.storage().instance().set(&PAUSED, &paused);
+}
+
+/// Get token contract address used for bets and payouts
+pub fn get_token(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&TOKEN)
+        .expect("Token not initialized")
+}
+
+/// Check if the token contract is set
+pub fn has_token(env: &Env) -> bool {
+    env.storage().instance().has(&TOKEN)
+}
+
+/// Set token contract address
+pub fn set_token(env: &Env, token: &Address) {
+    env.storage().instance().set(&TOKEN, token);
+}
+
+/// Default game configuration
+pub fn default_config() -> Config {
+    Config {
+        house_edge_bps: DEFAULT_HOUSE_EDGE_BPS,
+        min_bet: DEFAULT_MIN_BET,
+        max_bet: DEFAULT_MAX_BET,
+        max_multiplier: DEFAULT_MAX_MULTIPLIER,
+        max_client_seeds: DEFAULT_MAX_CLIENT_SEEDS,
+        waiting_timeout: DEFAULT_WAITING_TIMEOUT,
+        in_progress_timeout: DEFAULT_IN_PROGRESS_TIMEOUT,
+        max_exposure_bps: DEFAULT_MAX_EXPOSURE_BPS,
+        unrevealed_penalty_bps: DEFAULT_UNREVEALED_PENALTY_BPS,
+        mix_ledger_entropy: DEFAULT_MIX_LEDGER_ENTROPY,
+        max_bets_per_player: DEFAULT_MAX_BETS_PER_PLAYER,
+        max_bets_per_round: DEFAULT_MAX_BETS_PER_ROUND,
+        min_reveal_window: DEFAULT_MIN_REVEAL_WINDOW,
+    }
+}
+
+/// Get game configuration
+pub fn get_config(env: &Env) -> Config {
+    env.storage()
+        .instance()
+        .get(&CONFIG)
+        .unwrap_or_else(default_config)
+}
+
+/// Set game configuration
+pub fn set_config(env: &Env, config: &Config) {
+    env.storage().instance().set(&CONFIG, config);
+}
+
+/// Check if round exists
+pub fn has_round(env: &Env, round_id: u64) -> bool {
+    env.storage().persistent().has(&DataKey::Round(round_id))
+}
+
 /// Get round by ID
 pub fn get_round(env: &Env, round_id: u64) -> Result<Round, Error> {
     env.storage()
         .persistent()
-        .get(&round_id)
+        .get(&DataKey::Round(round_id))
         .ok_or(Error::RoundNotFound)
 }
 
 /// Set round
 pub fn set_round(env: &Env, round_id: u64, round: &Round) {
-    env.storage().persistent().set(&round_id, round);
+    let key = DataKey::Round(round_id);
+    env.storage().persistent().set(&key, round);
+    extend_persistent(env, &key);
+}
+
+/// Check if bet exists
+pub fn has_bet(env: &Env, bet_id: u64) -> bool {
+    env.storage().persistent().has(&DataKey::Bet(bet_id))
 }
 
 /// Get bet by ID
 pub fn get_bet(env: &Env, bet_id: u64) -> Result<Bet, Error> {
     env.storage()
         .persistent()
-        .get(&bet_id)
+        .get(&DataKey::Bet(bet_id))
         .ok_or(Error::BetNotFound)
 }
 
 /// Set bet
 pub fn set_bet(env: &Env, bet_id: u64, bet: &Bet) {
-    env.storage().persistent().set(&bet_id, bet);
+    let key = DataKey::Bet(bet_id);
+    env.storage().persistent().set(&key, bet);
+    extend_persistent(env, &key);
+}
+
+/// Get ids of all bets placed in a round
+pub fn get_round_bets(env: &Env, round_id: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RoundBets(round_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Add a bet id to its round's index
+pub fn add_round_bet(env: &Env, round_id: u64, bet_id: u64) {
+    let mut bet_ids = get_round_bets(env, round_id);
+    bet_ids.push_back(bet_id);
+    let key = DataKey::RoundBets(round_id);
+    env.storage().persistent().set(&key, &bet_ids);
+    extend_persistent(env, &key);
+}
+
+/// Get number of bets a player placed
+pub fn get_player_bet_count(env: &Env, player: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PlayerBetCount(player.clone()))
+        .unwrap_or(0)
+}
+
+/// Get a chunk of a player's bet ids
+pub fn get_player_bet_chunk(env: &Env, player: &Address, chunk: u32) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PlayerBets(player.clone(), chunk))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Append a bet id to a player's index
+///
+/// Ids are stored in chunks of `PLAYER_BETS_CHUNK_SIZE` so no single entry
+/// grows with the player's history.
+pub fn add_player_bet(env: &Env, player: &Address, bet_id: u64) {
+    let count = get_player_bet_count(env, player);
+    let chunk = count / PLAYER_BETS_CHUNK_SIZE;
+    let mut bet_ids = get_player_bet_chunk(env, player, chunk);
+    bet_ids.push_back(bet_id);
+
+    let chunk_key = DataKey::PlayerBets(player.clone(), chunk);
+    env.storage().persistent().set(&chunk_key, &bet_ids);
+    extend_persistent(env, &chunk_key);
+
+    let count_key = DataKey::PlayerBetCount(player.clone());
+    env.storage().persistent().set(&count_key, &(count + 1));
+    extend_persistent(env, &count_key);
+}
+
+/// Get lifetime stats of a player
+pub fn get_player_stats(env: &Env, player: &Address) -> PlayerStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PlayerStats(player.clone()))
+        .unwrap_or(PlayerStats {
+            total_wagered: 0,
+            total_won: 0,
+            rounds_played: 0,
+            biggest_multiplier: 0,
+            biggest_payout: 0,
+            net_pnl: 0,
+            last_played: 0,
+        })
+}
+
+/// Set lifetime stats of a player
+pub fn set_player_stats(env: &Env, player: &Address, stats: &PlayerStats) {
+    let key = DataKey::PlayerStats(player.clone());
+    env.storage().persistent().set(&key, stats);
+    extend_persistent(env, &key);
+}
+
+/// Get id of the last round created (0 before the first round)
+pub fn get_last_round_id(env: &Env) -> u64 {
+    env.storage().instance().get(&LAST_ROUND).unwrap_or(0)
+}
+
+/// Set id of the last round created
+pub fn set_last_round_id(env: &Env, round_id: u64) {
+    env.storage().instance().set(&LAST_ROUND, &round_id);
+}
+
+/// Get committed seed chain, if any
+pub fn get_seed_chain(env: &Env) -> Option<SeedChain> {
+    env.storage().instance().get(&SEED_CHAIN)
+}
+
+/// Set committed seed chain
+pub fn set_seed_chain(env: &Env, chain: &SeedChain) {
+    env.storage().instance().set(&SEED_CHAIN, chain);
+}
+
+/// Get commitment to a bet's client seed, if not revealed yet
+pub fn get_seed_commitment(env: &Env, bet_id: u64) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SeedCommitment(bet_id))
+}
+
+/// Set commitment to a bet's client seed
+pub fn set_seed_commitment(env: &Env, bet_id: u64, commitment: &BytesN<32>) {
+    let key = DataKey::SeedCommitment(bet_id);
+    env.storage().persistent().set(&key, commitment);
+    extend_persistent(env, &key);
+}
+
+/// Clear commitment to a bet's client seed
+pub fn remove_seed_commitment(env: &Env, bet_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::SeedCommitment(bet_id));
+}
+
+/// Get client seeds revealed in a round
+pub fn get_revealed_seeds(env: &Env, round_id: u64) -> Vec<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RevealedSeeds(round_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set client seeds revealed in a round
+pub fn set_revealed_seeds(env: &Env, round_id: u64, seeds: &Vec<BytesN<32>>) {
+    let key = DataKey::RevealedSeeds(round_id);
+    env.storage().persistent().set(&key, seeds);
+    extend_persistent(env, &key);
+}
+
+/// Get ledger entropy mixed into a round, if any
+pub fn get_round_entropy(env: &Env, round_id: u64) -> Option<RoundEntropy> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RoundEntropy(round_id))
+}
+
+/// Set ledger entropy mixed into a round
+pub fn set_round_entropy(env: &Env, round_id: u64, entropy: &RoundEntropy) {
+    let key = DataKey::RoundEntropy(round_id);
+    env.storage().persistent().set(&key, entropy);
+    extend_persistent(env, &key);
+}
+
+/// Get seed chain position of a round's server seed, if chained
+pub fn get_round_chain_link(env: &Env, round_id: u64) -> Option<SeedChainLink> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RoundChainLink(round_id))
+}
+
+/// Set seed chain position of a round's server seed
+pub fn set_round_chain_link(env: &Env, round_id: u64, link: &SeedChainLink) {
+    let key = DataKey::RoundChainLink(round_id);
+    env.storage().persistent().set(&key, link);
+    extend_persistent(env, &key);
+}
+
+/// Get ids of the bets a player placed in a round
+pub fn get_player_round_bets(env: &Env, player: &Address, round_id: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PlayerRoundBets(player.clone(), round_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Add a bet id to the bets a player placed in a round
+pub fn add_player_round_bet(env: &Env, player: &Address, round_id: u64, bet_id: u64) {
+    let mut bet_ids = get_player_round_bets(env, player, round_id);
+    bet_ids.push_back(bet_id);
+    let key = DataKey::PlayerRoundBets(player.clone(), round_id);
+    env.storage().persistent().set(&key, &bet_ids);
+    extend_persistent(env, &key);
+}
+
+/// Get number of rounds waiting or in progress
+pub fn get_open_rounds(env: &Env) -> u32 {
+    env.storage().instance().get(&OPEN_ROUNDS).unwrap_or(0)
+}
+
+/// Set number of rounds waiting or in progress
+pub fn set_open_rounds(env: &Env, count: u32) {
+    env.storage().instance().set(&OPEN_ROUNDS, &count);
+}
+
+/// Get LP shares held by a provider
+pub fn get_lp_shares(env: &Env, provider: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LpShares(provider.clone()))
+        .unwrap_or(0)
+}
+
+/// Set LP shares held by a provider
+pub fn set_lp_shares(env: &Env, provider: &Address, shares: i128) {
+    let key = DataKey::LpShares(provider.clone());
+    if shares == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &shares);
+        extend_persistent(env, &key);
+    }
+}
+
+/// Get LP shares a provider queued for withdrawal
+pub fn get_queued_withdrawal(env: &Env, provider: &Address) -> Option<QueuedWithdrawal> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PendingWithdrawal(provider.clone()))
+}
+
+/// Set LP shares a provider queued for withdrawal
+pub fn set_queued_withdrawal(env: &Env, provider: &Address, queued: &QueuedWithdrawal) {
+    let key = DataKey::PendingWithdrawal(provider.clone());
+    env.storage().persistent().set(&key, queued);
+    extend_persistent(env, &key);
+}
+
+/// Remove a provider's queued withdrawal
+pub fn remove_queued_withdrawal(env: &Env, provider: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PendingWithdrawal(provider.clone()));
+}
+
+/// Get token amount a provider can claim from settled withdrawals
+pub fn get_claimable_withdrawal(env: &Env, provider: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ClaimableWithdrawal(provider.clone()))
+        .unwrap_or(0)
+}
+
+/// Set token amount a provider can claim from settled withdrawals
+pub fn set_claimable_withdrawal(env: &Env, provider: &Address, amount: i128) {
+    let key = DataKey::ClaimableWithdrawal(provider.clone());
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &amount);
+        extend_persistent(env, &key);
+    }
+}
+
+/// Get tokens credited to a player that weren't transferred yet
+pub fn get_unpaid_payout(env: &Env, player: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::UnpaidPayout(player.clone()))
+        .unwrap_or(0)
+}
+
+/// Set tokens credited to a player that weren't transferred yet
+pub fn set_unpaid_payout(env: &Env, player: &Address, amount: i128) {
+    let key = DataKey::UnpaidPayout(player.clone());
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &amount);
+        extend_persistent(env, &key);
+    }
+}
+
+/// Get withdrawal epoch new withdrawals are queued in
+pub fn get_withdrawal_epoch(env: &Env) -> u32 {
+    env.storage().instance().get(&WITHDRAWAL_EPOCH).unwrap_or(0)
+}
+
+/// Set withdrawal epoch new withdrawals are queued in
+pub fn set_withdrawal_epoch(env: &Env, epoch: u32) {
+    env.storage().instance().set(&WITHDRAWAL_EPOCH, &epoch);
+}
+
+/// Get LP shares queued in the current withdrawal epoch
+pub fn get_queued_shares(env: &Env) -> i128 {
+    env.storage().instance().get(&QUEUED_SHARES).unwrap_or(0)
+}
+
+/// Set LP shares queued in the current withdrawal epoch
+pub fn set_queued_shares(env: &Env, shares: i128) {
+    env.storage().instance().set(&QUEUED_SHARES, &shares);
+}
+
+/// Get settled share price of a closed withdrawal epoch
+pub fn get_settled_epoch(env: &Env, epoch: u32) -> Option<SettledEpoch> {
+    let key = DataKey::WithdrawalEpoch(epoch);
+    let settled = env.storage().persistent().get(&key);
+    if settled.is_some() {
+        extend_persistent(env, &key);
+    }
+    settled
+}
+
+/// Set settled share price of a closed withdrawal epoch
+pub fn set_settled_epoch(env: &Env, epoch: u32, settled: &SettledEpoch) {
+    let key = DataKey::WithdrawalEpoch(epoch);
+    env.storage().persistent().set(&key, settled);
+    extend_persistent(env, &key);
+}
+
+/// Move an entry stored under a bare `u64` key to its typed key
+///
+/// Rounds and bets are told apart by their fields (`crash_multiplier` only
+/// exists on rounds, `player` only on bets), so entries written by older
+/// versions of the structs are moved as-is. Returns false if there is
+/// nothing recognizable to migrate. A typed entry is never overwritten.
+///
+/// Legacy stakes were never escrowed, and legacy rounds were never counted
+/// as open, so only settled entries are moved: a round that hasn't ended or
+/// a bet still active fails with `UnsettledLegacyEntry`.
+pub fn migrate_legacy_entry(env: &Env, id: u64) -> Result<bool, Error> {
+    let storage = env.storage().persistent();
+    let Some(value) = storage.get::<u64, Val>(&id) else {
+        return Ok(false);
+    };
+    let Ok(fields) = Map::<Symbol, Val>::try_from_val(env, &value) else {
+        return Ok(false);
+    };
+    let status = fields.get(Symbol::new(env, "status"));
+
+    let key = if fields.contains_key(Symbol::new(env, "crash_multiplier")) {
+        let status = status.and_then(|status| RoundStatus::try_from_val(env, &status).ok());
+        if !matches!(status, Some(RoundStatus::Ended | RoundStatus::Cancelled)) {
+            return Err(Error::UnsettledLegacyEntry);
+        }
+        DataKey::Round(id)
+    } else if fields.contains_key(Symbol::new(env, "player")) {
+        let status = status.and_then(|status| BetStatus::try_from_val(env, &status).ok());
+        if matches!(status, None | Some(BetStatus::Active)) {
+            return Err(Error::UnsettledLegacyEntry);
+        }
+        DataKey::Bet(id)
+    } else {
+        return Ok(false);
+    };
+
+    if !storage.has(&key) {
+        storage.set(&key, &value);
+    }
+    storage.remove(&id);
+    Ok(true)
+}
+
+/// Check if a stored struct has a field
+///
+/// Decoding a struct from a map with a different set of fields traps
+/// instead of returning an error, so layouts are told apart up front.
+fn has_field(env: &Env, value: &Val, name: &str) -> bool {
+    Map::<Symbol, Val>::try_from_val(env, value)
+        .map(|fields| fields.contains_key(Symbol::new(env, name)))
+        .unwrap_or(false)
+}
+
+/// Rewrite a round stored in an older layout under its typed key
+///
+/// Version 1 never stored the server seed or the settlement counts, so
+/// those stay empty. Config snapshots missing from older rounds are taken
+/// from the current config. Committed bets are counted from the seed
+/// commitments still stored; a round already in its reveal phase gets no
+/// reveal deadline. Returns false if the round is missing or already in the
+/// current layout.
+pub fn upgrade_round_layout(env: &Env, id: u64, config: &Config) -> bool {
+    let storage = env.storage().persistent();
+    let Some(value) = storage.get::<DataKey, Val>(&DataKey::Round(id)) else {
+        return false;
+    };
+    if has_field(env, &value, "in_progress_timeout") {
+        return false;
+    }
+    let old = if has_field(env, &value, "committed_bets") {
+        RoundV4::try_from_val(env, &value).unwrap()
+    } else {
+        upgrade_round_v3(env, id, &value, config)
+    };
+
+    let round = Round {
+        id: old.id,
+        status: old.status,
+        server_seed_hash: old.server_seed_hash,
+        server_seed: old.server_seed,
+        crash_multiplier: old.crash_multiplier,
+        house_edge_bps: old.house_edge_bps,
+        max_multiplier: old.max_multiplier,
+        mix_ledger_entropy: old.mix_ledger_entropy,
+        waiting_timeout: config.waiting_timeout,
+        in_progress_timeout: config.in_progress_timeout,
+        created_at: old.created_at,
+        started_at: old.started_at,
+        ended_at: old.ended_at,
+        total_bet_amount: old.total_bet_amount,
+        total_payout: old.total_payout,
+        bet_count: old.bet_count,
+        client_seeds: old.client_seeds,
+        winner_count: old.winner_count,
+        loser_count: old.loser_count,
+        house_profit: old.house_profit,
+        committed_bets: old.committed_bets,
+        reveal_ends_at: old.reveal_ends_at,
+    };
+    storage.set(&DataKey::Round(id), &round);
+    true
+}
+
+/// Bring a round stored before schema version 10 to the version 10 layout
+fn upgrade_round_v3(env: &Env, id: u64, value: &Val, config: &Config) -> RoundV4 {
+    let old = if has_field(env, value, "mix_ledger_entropy") {
+        RoundV3::try_from_val(env, value).unwrap()
+    } else {
+        let old = if has_field(env, value, "house_edge_bps") {
+            RoundV2::try_from_val(env, value).unwrap()
+        } else {
+            let old = RoundV1::try_from_val(env, value).unwrap();
+            let house_profit = if old.status == RoundStatus::Ended {
+                old.total_bet_amount - old.total_payout
+            } else {
+                0
+            };
+            RoundV2 {
+                id: old.id,
+                status: old.status,
+                server_seed_hash: old.server_seed_hash,
+                server_seed: None,
+                crash_multiplier: old.crash_multiplier,
+                house_edge_bps: config.house_edge_bps,
+                max_multiplier: config.max_multiplier,
+                created_at: old.created_at,
+                started_at: old.started_at,
+                ended_at: old.ended_at,
+                total_bet_amount: old.total_bet_amount,
+                total_payout: old.total_payout,
+                bet_count: old.bet_count,
+                client_seeds: old.client_seeds,
+                winner_count: 0,
+                loser_count: 0,
+                house_profit,
+            }
+        };
+        RoundV3 {
+            id: old.id,
+            status: old.status,
+            server_seed_hash: old.server_seed_hash,
+            server_seed: old.server_seed,
+            crash_multiplier: old.crash_multiplier,
+            house_edge_bps: old.house_edge_bps,
+            max_multiplier: old.max_multiplier,
+            mix_ledger_entropy: config.mix_ledger_entropy,
+            created_at: old.created_at,
+            started_at: old.started_at,
+            ended_at: old.ended_at,
+            total_bet_amount: old.total_bet_amount,
+            total_payout: old.total_payout,
+            bet_count: old.bet_count,
+            client_seeds: old.client_seeds,
+            winner_count: old.winner_count,
+            loser_count: old.loser_count,
+            house_profit: old.house_profit,
+        }
+    };
+
+    let committed_bets = get_round_bets(env, id)
+        .iter()
+        .filter(|bet_id| get_seed_commitment(env, *bet_id).is_some())
+        .count() as u32;
+    RoundV4 {
+        id: old.id,
+        status: old.status,
+        server_seed_hash: old.server_seed_hash,
+        server_seed: old.server_seed,
+        crash_multiplier: old.crash_multiplier,
+        house_edge_bps: old.house_edge_bps,
+        max_multiplier: old.max_multiplier,
+        mix_ledger_entropy: old.mix_ledger_entropy,
+        created_at: old.created_at,
+        started_at: old.started_at,
+        ended_at: old.ended_at,
+        total_bet_amount: old.total_bet_amount,
+        total_payout: old.total_payout,
+        bet_count: old.bet_count,
+        client_seeds: old.client_seeds,
+        winner_count: old.winner_count,
+        loser_count: old.loser_count,
+        house_profit: old.house_profit,
+        committed_bets,
+        reveal_ends_at: 0,
+    }
+}
+
+/// Rewrite a bet stored in an older layout under its typed key
+///
+/// Version 1 bets have no auto cash-out target, and bets before version 6
+/// have no partial cash-outs.
+pub fn upgrade_bet_layout(env: &Env, id: u64) -> bool {
+    let storage = env.storage().persistent();
+    let Some(value) = storage.get::<DataKey, Val>(&DataKey::Bet(id)) else {
+        return false;
+    };
+    if has_field(env, &value, "partial_cash_outs") {
+        return false;
+    }
+    let old = if has_field(env, &value, "auto_cash_out") {
+        BetV2::try_from_val(env, &value).unwrap()
+    } else {
+        let old = BetV1::try_from_val(env, &value).unwrap();
+        BetV2 {
+            id: old.id,
+            round_id: old.round_id,
+            player: old.player,
+            amount: old.amount,
+            cash_out_multiplier: old.cash_out_multiplier,
+            payout: old.payout,
+            status: old.status,
+            timestamp: old.timestamp,
+            auto_cash_out: None,
+        }
+    };
+
+    let bet = Bet {
+        id: old.id,
+        round_id: old.round_id,
+        player: old.player,
+        amount: old.amount,
+        cash_out_multiplier: old.cash_out_multiplier,
+        payout: old.payout,
+        status: old.status,
+        timestamp: old.timestamp,
+        auto_cash_out: old.auto_cash_out,
+        partial_cash_outs: Vec::new(env),
+    };
+    storage.set(&DataKey::Bet(id), &bet);
+    true
+}
+
+/// Rewrite the pool if it is still in the version 1 layout
+///
+/// Version 1 had no bankroll accounting, so it starts empty and gets
+/// funded through `deposit_liquidity`.
+pub fn upgrade_pool_layout(env: &Env) {
+    let Some(value) = env.storage().instance().get::<Symbol, Val>(&POOL) else {
+        return;
+    };
+    if has_field(env, &value, "bankroll") {
+        return;
+    }
+    let old = PoolV1::try_from_val(env, &value).unwrap();
+
+    set_pool(
+        env,
+        &Pool {
+            total_bets: old.total_bets,
+            total_payouts: old.total_payouts,
+            total_house_earnings: old.total_house_earnings,
+            bankroll: 0,
+            total_shares: 0,
+        },
+    );
+}
+
+/// Move the instance withdrawal queue of schema 8 and older into the
+/// current withdrawal epoch
+///
+/// Queued providers kept a bare share count under `PendingWithdrawal`,
+/// which now also records the epoch the shares are burned in.
+pub fn upgrade_withdrawal_queue(env: &Env) {
+    let Some(queue) = env.storage().instance().get::<Symbol, Vec<Address>>(&WITHDRAWALS) else {
+        return;
+    };
+    let epoch = get_withdrawal_epoch(env);
+    let mut queued_shares = get_queued_shares(env);
+    for provider in queue.iter() {
+        let key = DataKey::PendingWithdrawal(provider.clone());
+        let shares: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        if shares > 0 {
+            set_queued_withdrawal(env, &provider, &QueuedWithdrawal { shares, epoch });
+            queued_shares += shares;
+        }
+    }
+    set_queued_shares(env, queued_shares);
+    env.storage().instance().remove(&WITHDRAWALS);
+}
+
+/// Add config fields introduced after the config was stored, at their defaults
+pub fn upgrade_config_layout(env: &Env) {
+    let Some(value) = env.storage().instance().get::<Symbol, Val>(&CONFIG) else {
+        return;
+    };
+    let Ok(mut fields) = Map::<Symbol, Val>::try_from_val(env, &value) else {
+        return;
+    };
+
+    let added: [(&str, Val); 5] = [
+        ("unrevealed_penalty_bps", DEFAULT_UNREVEALED_PENALTY_BPS.into_val(env)),
+        ("mix_ledger_entropy", DEFAULT_MIX_LEDGER_ENTROPY.into_val(env)),
+        ("max_bets_per_player", DEFAULT_MAX_BETS_PER_PLAYER.into_val(env)),
+        ("max_bets_per_round", DEFAULT_MAX_BETS_PER_ROUND.into_val(env)),
+        ("min_reveal_window", DEFAULT_MIN_REVEAL_WINDOW.into_val(env)),
+    ];
+    let mut changed = false;
+    for (name, default) in added {
+        let key = Symbol::new(env, name);
+        if !fields.contains_key(key.clone()) {
+            fields.set(key, default);
+            changed = true;
+        }
+    }
+    if changed {
+        env.storage().instance().set(&CONFIG, &fields);
+    }
+}
+
+/// Get storage schema version (deployments before versioning are version 1)
+pub fn get_version(env: &Env) -> u32 {
+    env.storage().instance().get(&VERSION).unwrap_or(1)
+}
+
+/// Set storage schema version
+pub fn set_version(env: &Env, version: u32) {
+    env.storage().instance().set(&VERSION, &version);
 }
 
 /// Get pool statistics
@@ -62,6 +921,8 @@ pub fn get_pool(env: &Env) -> Pool {
             total_bets: 0,
             total_payouts: 0,
             total_house_earnings: 0,
+            bankroll: 0,
+            total_shares: 0,
         })
 }
 
@@ -69,4 +930,3 @@ pub fn get_pool(env: &Env) -> Pool {
 pub fn set_pool(env: &Env, pool: &Pool) {
     env.storage().instance().set(&POOL, pool);
 }
-
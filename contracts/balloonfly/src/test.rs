@@ -1,7 +1,24 @@
-#![cfg(test)]
-
 use super::*;
-use soroban_sdk::{testutils::{Address as _, Ledger}, BytesN, Env};
+use events::{
+    BetForfeited, BettingClosed, PartialCashedOut, SeedRevealed,
+    AdminProposed, AdminTransferred, BetPlaced, CashedOut, ConfigUpdated, ContractUpgraded, LiquidityDeposited, LiquidityWithdrawn, Paused, PayoutClaimed, RefundClaimed,
+    RoleSet, RoundCancelled, RoundCreated, RoundFinalized, RoundStarted, SeedChainCommitted, StorageMigrated, Unpaused, WithdrawalClaimed, WithdrawalQueued,
+};
+use soroban_sdk::{
+    testutils::{
+        storage::{Instance as _, Persistent as _},
+        Address as _, Events as _, IssuerFlags, Ledger, MockAuth, MockAuthInvoke,
+    },
+    token::{StellarAssetClient, TokenClient},
+    vec, BytesN, Env, Event, IntoVal, String, Val,
+};
+
+const HOUSE_BANKROLL: i128 = 10_000_000_000_000; // 1,000,000 XLM
+const PLAYER_BALANCE: i128 = 10_000_000_000; // 1,000 XLM
+
+// Server seeds with known crash points when combined with client seed 111
+const SERVER_SEED: u32 = 12345; // Crashes at 1.60x
+const HIGH_CRASH_SEED: u32 = 3; // Crashes at 9.70x
 
 fn create_test_contract<'a>(env: &'a Env) -> (Address, BalloonFlyContractClient<'a>) {
     env.mock_all_auths();
@@ -10,12 +27,29 @@ fn create_test_contract<'a>(env: &'a Env) -> (Address, BalloonFlyContractClient<
     env.ledger().set_timestamp(1000000);
 
     let admin = Address::generate(env);
-    let contract_id = env.register(BalloonFlyContract, (&admin,));
+    let token = env.register_stellar_asset_contract_v2(admin.clone());
+    // Lets tests freeze a player's balance
+    token.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let contract_id = env.register(BalloonFlyContract, (&admin, &token.address()));
     let client = BalloonFlyContractClient::new(env, &contract_id);
 
+    // Fund the house so it can pay out winners
+    StellarAssetClient::new(env, &token.address()).mint(&admin, &HOUSE_BANKROLL);
+    client.deposit_liquidity(&admin, &HOUSE_BANKROLL);
+
     (admin, client)
 }
 
+fn create_player(env: &Env, client: &BalloonFlyContractClient) -> Address {
+    let player = Address::generate(env);
+    StellarAssetClient::new(env, &client.token()).mint(&player, &PLAYER_BALANCE);
+    player
+}
+
+fn balance(env: &Env, client: &BalloonFlyContractClient, id: &Address) -> i128 {
+    TokenClient::new(env, &client.token()).balance(id)
+}
+
 fn generate_seed(env: &Env, value: u32) -> BytesN<32> {
     let mut bytes = [0u8; 32];
     bytes[0] = (value >> 24) as u8;
@@ -25,6 +59,40 @@ fn generate_seed(env: &Env, value: u32) -> BytesN<32> {
     BytesN::from_array(env, &bytes)
 }
 
+/// Events published by the game contract during the last invocation
+fn contract_events(
+    env: &Env,
+    client: &BalloonFlyContractClient,
+) -> soroban_sdk::Vec<(soroban_sdk::Vec<Val>, Val)> {
+    let mut events = soroban_sdk::Vec::new(env);
+    for (contract, topics, data) in env.events().all().iter() {
+        if contract == client.address {
+            events.push_back((topics, data));
+        }
+    }
+    events
+}
+
+fn to_event(env: &Env, event: &impl Event) -> (soroban_sdk::Vec<Val>, Val) {
+    (event.topics(env), event.data(env))
+}
+
+fn advance_time(env: &Env, seconds: u64) {
+    env.ledger().set_timestamp(env.ledger().timestamp() + seconds);
+}
+
+/// Move the ledger to the moment a started round's balloon crashes
+fn advance_to_crash(env: &Env, client: &BalloonFlyContractClient, round_id: u64) {
+    let round = client.get_round(&round_id);
+    let mut elapsed = 0;
+    while curve::multiplier_at(elapsed) < round.crash_multiplier {
+        elapsed += 1;
+    }
+    if env.ledger().timestamp() < round.started_at + elapsed {
+        env.ledger().set_timestamp(round.started_at + elapsed);
+    }
+}
+
 fn hash_seed(env: &Env, seed: &BytesN<32>) -> BytesN<32> {
     let seed_bytes: soroban_sdk::Bytes = seed.clone().into();
     let hash = env.crypto().sha256(&seed_bytes);
@@ -38,11 +106,13 @@ fn test_initialization() {
 
     let stored_admin = client.admin();
     assert_eq!(stored_admin, admin);
+    assert_eq!(balance(&env, &client, &client.address), HOUSE_BANKROLL);
 
     let pool = client.get_pool();
     assert_eq!(pool.total_bets, 0);
     assert_eq!(pool.total_payouts, 0);
     assert_eq!(pool.total_house_earnings, 0);
+    assert_eq!(pool.bankroll, HOUSE_BANKROLL);
 }
 
 #[test]
@@ -51,10 +121,10 @@ fn test_create_round() {
     let (_admin, client) = create_test_contract(&env);
 
     let round_id = 1u64;
-    let server_seed = generate_seed(&env, 12345);
+    let server_seed = generate_seed(&env, SERVER_SEED);
     let server_seed_hash = hash_seed(&env, &server_seed);
 
-    let round = client.create_round(&round_id, &server_seed_hash);
+    let round = client.create_round(&server_seed_hash);
     
     assert_eq!(round.id, round_id);
     assert_eq!(round.status, RoundStatus::Waiting);
@@ -65,19 +135,55 @@ fn test_create_round() {
 }
 
 #[test]
-fn test_create_duplicate_round() {
+fn test_round_ids_are_sequential() {
     let env = Env::default();
     let (_admin, client) = create_test_contract(&env);
 
-    let round_id = 1u64;
-    let server_seed = generate_seed(&env, 12345);
+    let server_seed = generate_seed(&env, SERVER_SEED);
     let server_seed_hash = hash_seed(&env, &server_seed);
+    assert_eq!(client.current_round(), None);
 
-    client.create_round(&round_id, &server_seed_hash);
-    
-    // Try to create same round again - should error
-    let result = client.try_create_round(&round_id, &server_seed_hash);
-    assert_eq!(result.err(), Some(Ok(Error::RoundAlreadyExists)));
+    let round = client.create_round(&server_seed_hash);
+    assert_eq!(round.id, 1);
+    assert_eq!(client.current_round(), Some(round));
+
+    // Previous round must finish first
+    let result = client.try_create_round(&server_seed_hash);
+    assert_eq!(result.err(), Some(Ok(Error::PreviousRoundOpen)));
+
+    client.start_round(&1, &server_seed);
+    assert_eq!(client.current_round().unwrap().status, RoundStatus::InProgress);
+    let result = client.try_create_round(&server_seed_hash);
+    assert_eq!(result.err(), Some(Ok(Error::PreviousRoundOpen)));
+
+    advance_to_crash(&env, &client, 1);
+
+    client.finalize_round(&1);
+    assert_eq!(client.current_round(), None);
+    assert_eq!(client.create_round(&server_seed_hash).id, 2);
+
+    // Cancelled rounds are finished too
+    client.cancel_round(&2);
+    assert_eq!(client.current_round(), None);
+    assert_eq!(client.create_round(&server_seed_hash).id, 3);
+    assert_eq!(client.current_round().unwrap().id, 3);
+}
+
+#[test]
+fn test_create_round_skips_migrated_ids() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    // Legacy round stored under its bare id
+    let server_seed_hash = hash_seed(&env, &generate_seed(&env, SERVER_SEED));
+    let round = client.create_round(&server_seed_hash);
+    client.cancel_round(&round.id);
+    let mut legacy = client.get_round(&round.id);
+    legacy.id = 5;
+    env.as_contract(&client.address, || env.storage().persistent().set(&5u64, &legacy));
+
+    client.migrate_storage(&vec![&env, 5u64]);
+    assert_eq!(client.create_round(&server_seed_hash).id, 6);
 }
 
 #[test]
@@ -86,16 +192,16 @@ fn test_start_round() {
     let (_admin, client) = create_test_contract(&env);
 
     let round_id = 1u64;
-    let server_seed = generate_seed(&env, 12345);
+    let server_seed = generate_seed(&env, SERVER_SEED);
     let server_seed_hash = hash_seed(&env, &server_seed);
-    let crash_multiplier = 250u64; // 2.50x
 
-    client.create_round(&round_id, &server_seed_hash);
-    client.start_round(&round_id, &server_seed, &crash_multiplier);
+    client.create_round(&server_seed_hash);
+    client.start_round(&round_id, &server_seed);
 
     let round = client.get_round(&round_id);
     assert_eq!(round.status, RoundStatus::InProgress);
-    assert_eq!(round.crash_multiplier, crash_multiplier);
+    assert_eq!(round.server_seed, Some(server_seed));
+    assert_eq!(round.crash_multiplier, 101); // No client seeds: 1.01x
     assert!(round.started_at > 0);
 }
 
@@ -105,31 +211,78 @@ fn test_start_round_wrong_seed() {
     let (_admin, client) = create_test_contract(&env);
 
     let round_id = 1u64;
-    let server_seed = generate_seed(&env, 12345);
+    let server_seed = generate_seed(&env, SERVER_SEED);
     let server_seed_hash = hash_seed(&env, &server_seed);
     let wrong_seed = generate_seed(&env, 99999); // Wrong seed!
-    let crash_multiplier = 250u64;
 
-    client.create_round(&round_id, &server_seed_hash);
+    client.create_round(&server_seed_hash);
     
     // This should error because seed doesn't match hash
-    let result = client.try_start_round(&round_id, &wrong_seed, &crash_multiplier);
+    let result = client.try_start_round(&round_id, &wrong_seed);
     assert_eq!(result.err(), Some(Ok(Error::InvalidServerSeedHash)));
 }
 
 #[test]
-fn test_start_round_invalid_multiplier() {
+fn test_crash_multiplier_derived_from_seeds() {
     let env = Env::default();
     let (_admin, client) = create_test_contract(&env);
 
     let round_id = 1u64;
-    let server_seed = generate_seed(&env, 12345);
+    let server_seed = generate_seed(&env, SERVER_SEED);
     let server_seed_hash = hash_seed(&env, &server_seed);
-    let crash_multiplier = 50u64; // Too low! Minimum is 100 (1.00x)
+    client.create_round(&server_seed_hash);
 
-    client.create_round(&round_id, &server_seed_hash);
-    let result = client.try_start_round(&round_id, &server_seed, &crash_multiplier);
-    assert_eq!(result.err(), Some(Ok(Error::InvalidMultiplier)));
+    for i in 1..=3u32 {
+        let player = create_player(&env, &client);
+        let client_seed = generate_seed(&env, i * 111);
+        client.place_bet(&player, &round_id, &100_000_000, &client_seed, &None);
+    }
+
+    client.start_round(&round_id, &server_seed);
+
+    // HMAC_SHA256(server_seed, seed_111 || seed_222 || seed_333) maps to 3.49x
+    let round = client.get_round(&round_id);
+    let hash = fairness::round_hash(&env, &server_seed, &round.client_seeds, None);
+    assert_eq!(fairness::crash_multiplier(&hash, 300, 10_000), 349);
+    assert_eq!(round.crash_multiplier, 349);
+}
+
+#[test]
+fn test_hmac_sha256_rfc4231() {
+    let env = Env::default();
+
+    // RFC 4231 test case 2 uses a short key, pad it to 32 bytes with zeros
+    // which HMAC does internally anyway
+    let mut key = [0u8; 32];
+    key[..4].copy_from_slice(b"Jefe");
+    let message = soroban_sdk::Bytes::from_slice(&env, b"what do ya want for nothing?");
+    let expected: [u8; 32] = [
+        0x5b, 0xdc, 0xc1, 0x46, 0xbf, 0x60, 0x75, 0x4e, 0x6a, 0x04, 0x24, 0x26, 0x08, 0x95,
+        0x75, 0xc7, 0x5a, 0x00, 0x3f, 0x08, 0x9d, 0x27, 0x39, 0x83, 0x9d, 0xec, 0x58, 0xb9,
+        0x64, 0xec, 0x38, 0x43,
+    ];
+
+    let mac = fairness::hmac_sha256(&env, &BytesN::from_array(&env, &key), &message);
+    assert_eq!(mac.to_array(), expected);
+}
+
+#[test]
+fn test_crash_multiplier_distribution_bounds() {
+    let env = Env::default();
+
+    // Lowest hash crashes immediately (house edge keeps it below 1.00x)
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+    assert_eq!(fairness::crash_multiplier(&zero, 300, 10_000), 100);
+
+    // Half way through the range: 0.97 * 2 = 1.94x
+    let mut half = [0u8; 32];
+    half[0] = 0x80;
+    let half = BytesN::from_array(&env, &half);
+    assert_eq!(fairness::crash_multiplier(&half, 300, 10_000), 194);
+
+    // Highest hash is capped by the max multiplier
+    let max = BytesN::from_array(&env, &[0xffu8; 32]);
+    assert_eq!(fairness::crash_multiplier(&max, 300, 10_000), 10_000);
 }
 
 #[test]
@@ -138,14 +291,14 @@ fn test_place_bet() {
     let (_admin, client) = create_test_contract(&env);
 
     let round_id = 1u64;
-    let server_seed_hash = hash_seed(&env, &generate_seed(&env, 12345));
-    client.create_round(&round_id, &server_seed_hash);
+    let server_seed_hash = hash_seed(&env, &generate_seed(&env, SERVER_SEED));
+    client.create_round(&server_seed_hash);
 
-    let player = Address::generate(&env);
+    let player = create_player(&env, &client);
     let bet_amount = 100_000_000i128; // 10 XLM
     let client_seed = generate_seed(&env, 111);
 
-    let bet_id = client.place_bet(&player, &round_id, &bet_amount, &client_seed);
+    let bet_id = client.place_bet(&player, &round_id, &bet_amount, &client_seed, &None);
     assert!(bet_id > 0);
 
     let bet = client.get_bet(&bet_id);
@@ -168,14 +321,14 @@ fn test_place_bet_too_small() {
     let (_admin, client) = create_test_contract(&env);
 
     let round_id = 1u64;
-    let server_seed_hash = hash_seed(&env, &generate_seed(&env, 12345));
-    client.create_round(&round_id, &server_seed_hash);
+    let server_seed_hash = hash_seed(&env, &generate_seed(&env, SERVER_SEED));
+    client.create_round(&server_seed_hash);
 
-    let player = Address::generate(&env);
+    let player = create_player(&env, &client);
     let bet_amount = 1_000_000i128; // 0.1 XLM - too small!
     let client_seed = generate_seed(&env, 111);
 
-    let result = client.try_place_bet(&player, &round_id, &bet_amount, &client_seed);
+    let result = client.try_place_bet(&player, &round_id, &bet_amount, &client_seed, &None);
     assert_eq!(result.err(), Some(Ok(Error::InvalidBetAmount)));
 }
 
@@ -185,14 +338,14 @@ fn test_place_bet_too_large() {
     let (_admin, client) = create_test_contract(&env);
 
     let round_id = 1u64;
-    let server_seed_hash = hash_seed(&env, &generate_seed(&env, 12345));
-    client.create_round(&round_id, &server_seed_hash);
+    let server_seed_hash = hash_seed(&env, &generate_seed(&env, SERVER_SEED));
+    client.create_round(&server_seed_hash);
 
-    let player = Address::generate(&env);
+    let player = create_player(&env, &client);
     let bet_amount = 2_000_000_000_000i128; // 200,000 XLM - too large!
     let client_seed = generate_seed(&env, 111);
 
-    let result = client.try_place_bet(&player, &round_id, &bet_amount, &client_seed);
+    let result = client.try_place_bet(&player, &round_id, &bet_amount, &client_seed, &None);
     assert_eq!(result.err(), Some(Ok(Error::InvalidBetAmount)));
 }
 
@@ -202,18 +355,102 @@ fn test_place_bet_duplicate() {
     let (_admin, client) = create_test_contract(&env);
 
     let round_id = 1u64;
-    let server_seed_hash = hash_seed(&env, &generate_seed(&env, 12345));
-    client.create_round(&round_id, &server_seed_hash);
+    let server_seed_hash = hash_seed(&env, &generate_seed(&env, SERVER_SEED));
+    client.create_round(&server_seed_hash);
 
-    let player = Address::generate(&env);
+    let player = create_player(&env, &client);
     let bet_amount = 100_000_000i128;
     let client_seed = generate_seed(&env, 111);
 
-    client.place_bet(&player, &round_id, &bet_amount, &client_seed);
+    client.place_bet(&player, &round_id, &bet_amount, &client_seed, &None);
     
     // Try to bet again - should error
-    let result = client.try_place_bet(&player, &round_id, &bet_amount, &client_seed);
+    let result = client.try_place_bet(&player, &round_id, &bet_amount, &client_seed, &None);
+    assert_eq!(result.err(), Some(Ok(Error::BetAlreadyPlaced)));
+}
+
+#[test]
+fn test_multiple_bets_per_player() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+    client.set_config(&Config {
+        max_bets_per_player: 2,
+        ..client.get_config()
+    });
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    client.create_round(&hash_seed(&env, &server_seed));
+
+    // Round crashes at 2.00x: the 1.50x bet wins, the 2.50x bet loses
+    let player = create_player(&env, &client);
+    let bet_amount = 100_000_000i128;
+    let seed = generate_seed(&env, 111);
+    let low_bet = client.place_bet(&player, &round_id, &bet_amount, &seed, &Some(150));
+    let high_bet = client.place_bet(&player, &round_id, &bet_amount, &seed, &Some(250));
+    assert_ne!(low_bet, high_bet);
+
+    let result = client.try_place_bet(&player, &round_id, &bet_amount, &seed, &None);
     assert_eq!(result.err(), Some(Ok(Error::BetAlreadyPlaced)));
+
+    let bets = client.get_player_round_bets(&player, &round_id);
+    assert_eq!(bets.len(), 2);
+    assert_eq!(bets.get(0).unwrap().id, low_bet);
+    assert_eq!(bets.get(1).unwrap().id, high_bet);
+    assert_eq!(client.get_player_bet_count(&player), 2);
+
+    client.start_round(&round_id, &server_seed);
+    advance_time(&env, 10_000);
+    client.finalize_round(&round_id);
+
+    assert_eq!(client.get_bet(&low_bet).status, BetStatus::CashedOut);
+    assert_eq!(client.get_bet(&high_bet).status, BetStatus::Lost);
+    assert_eq!(
+        balance(&env, &client, &player),
+        PLAYER_BALANCE - 2 * bet_amount + 145_500_000
+    );
+
+    // Both bets count as a single round played
+    let stats = client.get_player_stats(&player);
+    assert_eq!(stats.rounds_played, 1);
+    assert_eq!(stats.total_wagered, 2 * bet_amount);
+    assert_eq!(stats.net_pnl, 145_500_000 - 2 * bet_amount);
+
+    // The round stops counting once every bet in it is refunded
+    client.create_round(&hash_seed(&env, &server_seed));
+    let commitment = hash_seed(&env, &seed);
+    let first = client.place_committed_bet(&player, &2, &bet_amount, &commitment, &None);
+    let second = client.place_committed_bet(&player, &2, &bet_amount, &commitment, &None);
+    assert_eq!(client.get_player_stats(&player).rounds_played, 2);
+    client.cancel_round(&2);
+    client.claim_refund(&first);
+    assert_eq!(client.get_player_stats(&player).rounds_played, 2);
+    client.claim_refund(&second);
+    let refunded = client.get_player_stats(&player);
+    assert_eq!(refunded.rounds_played, 1);
+    assert_eq!(refunded.net_pnl, stats.net_pnl);
+}
+
+#[test]
+fn test_place_bet_round_full() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+    client.set_config(&Config {
+        max_bets_per_round: 2,
+        ..client.get_config()
+    });
+
+    let round_id = 1u64;
+    client.create_round(&hash_seed(&env, &generate_seed(&env, SERVER_SEED)));
+    let seed = generate_seed(&env, 111);
+    for _ in 0..2 {
+        let player = create_player(&env, &client);
+        client.place_bet(&player, &round_id, &100_000_000, &seed, &None);
+    }
+
+    let late = create_player(&env, &client);
+    let result = client.try_place_bet(&late, &round_id, &100_000_000, &seed, &None);
+    assert_eq!(result.err(), Some(Ok(Error::RoundFull)));
 }
 
 #[test]
@@ -222,17 +459,17 @@ fn test_place_bet_after_start() {
     let (_admin, client) = create_test_contract(&env);
 
     let round_id = 1u64;
-    let server_seed = generate_seed(&env, 12345);
+    let server_seed = generate_seed(&env, SERVER_SEED);
     let server_seed_hash = hash_seed(&env, &server_seed);
-    client.create_round(&round_id, &server_seed_hash);
-    client.start_round(&round_id, &server_seed, &250);
+    client.create_round(&server_seed_hash);
+    client.start_round(&round_id, &server_seed);
 
-    let player = Address::generate(&env);
+    let player = create_player(&env, &client);
     let bet_amount = 100_000_000i128;
     let client_seed = generate_seed(&env, 111);
 
     // Try to bet after round started - should error
-    let result = client.try_place_bet(&player, &round_id, &bet_amount, &client_seed);
+    let result = client.try_place_bet(&player, &round_id, &bet_amount, &client_seed, &None);
     assert_eq!(result.err(), Some(Ok(Error::InvalidRoundStatus)));
 }
 
@@ -243,27 +480,28 @@ fn test_cash_out() {
 
     // Setup round
     let round_id = 1u64;
-    let server_seed = generate_seed(&env, 12345);
+    let server_seed = generate_seed(&env, SERVER_SEED);
     let server_seed_hash = hash_seed(&env, &server_seed);
-    let crash_multiplier = 500u64; // 5.00x
-    client.create_round(&round_id, &server_seed_hash);
+    client.create_round(&server_seed_hash);
 
     // Place bet
-    let player = Address::generate(&env);
+    let player = create_player(&env, &client);
     let bet_amount = 100_000_000i128; // 10 XLM
     let client_seed = generate_seed(&env, 111);
-    let bet_id = client.place_bet(&player, &round_id, &bet_amount, &client_seed);
+    let bet_id = client.place_bet(&player, &round_id, &bet_amount, &client_seed, &None);
 
-    // Start round
-    client.start_round(&round_id, &server_seed, &crash_multiplier);
+    // Start round (crashes at 1.60x)
+    client.start_round(&round_id, &server_seed);
 
-    // Cash out at 2.00x
-    let cash_out_multiplier = 200u64;
-    let payout = client.cash_out(&player, &bet_id, &cash_out_multiplier);
+    // Cash out after 7 seconds: 1.06^7 = 1.50x
+    advance_time(&env, 7);
+    let cash_out_multiplier = 150u64;
+    assert_eq!(client.get_multiplier(&round_id), cash_out_multiplier);
+    let payout = client.cash_out(&player, &bet_id);
 
-    // Expected: 10 XLM * 2.00x = 20 XLM
-    // With 3% house edge: 20 * 0.97 = 19.4 XLM
-    let expected_payout = 194_000_000i128;
+    // Expected: 10 XLM * 1.50x = 15 XLM
+    // With 3% house edge: 15 * 0.97 = 14.55 XLM
+    let expected_payout = 145_500_000i128;
     assert_eq!(payout, expected_payout);
 
     let bet = client.get_bet(&bet_id);
@@ -273,7 +511,7 @@ fn test_cash_out() {
 
     let pool = client.get_pool();
     assert_eq!(pool.total_payouts, expected_payout);
-    assert_eq!(pool.total_house_earnings, 6_000_000i128); // 3% of 20 XLM
+    assert_eq!(pool.total_house_earnings, 4_500_000i128); // 3% of 15 XLM
 }
 
 #[test]
@@ -282,46 +520,183 @@ fn test_cash_out_after_crash() {
     let (_admin, client) = create_test_contract(&env);
 
     let round_id = 1u64;
-    let server_seed = generate_seed(&env, 12345);
+    let server_seed = generate_seed(&env, SERVER_SEED);
     let server_seed_hash = hash_seed(&env, &server_seed);
-    let crash_multiplier = 200u64; // 2.00x
-    client.create_round(&round_id, &server_seed_hash);
+    client.create_round(&server_seed_hash);
 
-    let player = Address::generate(&env);
+    let player = create_player(&env, &client);
     let bet_amount = 100_000_000i128;
     let client_seed = generate_seed(&env, 111);
-    let bet_id = client.place_bet(&player, &round_id, &bet_amount, &client_seed);
+    let bet_id = client.place_bet(&player, &round_id, &bet_amount, &client_seed, &None);
 
-    client.start_round(&round_id, &server_seed, &crash_multiplier);
+    client.start_round(&round_id, &server_seed);
 
-    // Try to cash out at 2.50x when crash is at 2.00x - should error
-    let result = client.try_cash_out(&player, &bet_id, &250);
+    // After 12 seconds the curve is at 2.01x, past the 1.60x crash - should error
+    advance_time(&env, 12);
+    let result = client.try_cash_out(&player, &bet_id);
     assert_eq!(result.err(), Some(Ok(Error::AlreadyCrashed)));
 }
 
+#[test]
+fn test_cash_out_partial() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    client.create_round(&hash_seed(&env, &server_seed));
+
+    let player = create_player(&env, &client);
+    let bet_amount = 100_000_000i128;
+    let bet_id = client.place_bet(&player, &round_id, &bet_amount, &generate_seed(&env, 111), &None);
+    client.start_round(&round_id, &server_seed);
+
+    // Half the stake at 1.50x: 5 XLM * 1.50x * 0.97 = 7.275 XLM
+    advance_time(&env, 7);
+    assert_eq!(client.cash_out_partial(&player, &bet_id, &5_000), 72_750_000);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![
+            &env,
+            to_event(
+                &env,
+                &PartialCashedOut {
+                    round_id,
+                    player: player.clone(),
+                    bet_id,
+                    amount: 50_000_000,
+                    multiplier: 150,
+                    payout: 72_750_000,
+                }
+            ),
+        ]
+    );
+
+    let bet = client.get_bet(&bet_id);
+    assert_eq!(bet.status, BetStatus::Active);
+    assert_eq!(bet.payout, 72_750_000);
+    assert_eq!(
+        bet.partial_cash_outs,
+        vec![
+            &env,
+            PartialCashOut {
+                amount: 50_000_000,
+                multiplier: 150,
+                payout: 72_750_000,
+                timestamp: env.ledger().timestamp(),
+            },
+        ]
+    );
+
+    // The rest rides into the 1.60x crash and is lost
+    advance_time(&env, 5);
+    let result = client.try_cash_out(&player, &bet_id);
+    assert_eq!(result.err(), Some(Ok(Error::AlreadyCrashed)));
+    client.finalize_round(&round_id);
+
+    let bet = client.get_bet(&bet_id);
+    assert_eq!(bet.status, BetStatus::Lost);
+    assert_eq!(bet.payout, 72_750_000);
+    assert_eq!(balance(&env, &client, &player), PLAYER_BALANCE - bet_amount + 72_750_000);
+
+    let round = client.get_round(&round_id);
+    assert_eq!(round.total_payout, 72_750_000);
+    assert_eq!(round.loser_count, 1);
+    assert_eq!(round.house_profit, bet_amount - 72_750_000);
+    assert_eq!(client.get_pool().total_payouts, 72_750_000);
+    assert_eq!(client.get_player_stats(&player).net_pnl, 72_750_000 - bet_amount);
+}
+
+#[test]
+fn test_cash_out_partial_then_full() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    client.create_round(&hash_seed(&env, &server_seed));
+
+    let player = create_player(&env, &client);
+    let bet_amount = 100_000_000i128;
+    let bet_id = client.place_bet(&player, &round_id, &bet_amount, &generate_seed(&env, 111), &None);
+    client.start_round(&round_id, &server_seed);
+
+    // A quarter at 1.50x, then the remaining 7.5 XLM at the same multiplier
+    advance_time(&env, 7);
+    assert_eq!(client.cash_out_partial(&player, &bet_id, &2_500), 36_375_000);
+    assert_eq!(client.cash_out(&player, &bet_id), 109_125_000);
+
+    let bet = client.get_bet(&bet_id);
+    assert_eq!(bet.status, BetStatus::CashedOut);
+    assert_eq!(bet.cash_out_multiplier, 150);
+    assert_eq!(bet.payout, 145_500_000);
+    assert_eq!(bet.partial_cash_outs.len(), 1);
+
+    advance_time(&env, 5);
+    client.finalize_round(&round_id);
+    let round = client.get_round(&round_id);
+    assert_eq!(round.total_payout, 145_500_000);
+    assert_eq!(round.winner_count, 1);
+    assert_eq!(balance(&env, &client, &player), PLAYER_BALANCE - bet_amount + 145_500_000);
+}
+
+#[test]
+fn test_cash_out_partial_validation() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    client.create_round(&hash_seed(&env, &server_seed));
+
+    let player = create_player(&env, &client);
+    let bet_id = client.place_bet(&player, &round_id, &100_000_000, &generate_seed(&env, 111), &None);
+
+    let result = client.try_cash_out_partial(&player, &bet_id, &5_000);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidRoundStatus)));
+
+    client.start_round(&round_id, &server_seed);
+    advance_time(&env, 7);
+    for fraction_bps in [0u32, 10_001] {
+        let result = client.try_cash_out_partial(&player, &bet_id, &fraction_bps);
+        assert_eq!(result.err(), Some(Ok(Error::InvalidCashOutFraction)));
+    }
+
+    for _ in 0..storage::MAX_PARTIAL_CASH_OUTS {
+        client.cash_out_partial(&player, &bet_id, &1_000);
+    }
+    let result = client.try_cash_out_partial(&player, &bet_id, &1_000);
+    assert_eq!(result.err(), Some(Ok(Error::TooManyPartialCashOuts)));
+
+    // The whole remaining stake can still leave
+    client.cash_out_partial(&player, &bet_id, &10_000);
+    assert_eq!(client.get_bet(&bet_id).status, BetStatus::CashedOut);
+}
+
 #[test]
 fn test_double_cash_out() {
     let env = Env::default();
     let (_admin, client) = create_test_contract(&env);
 
     let round_id = 1u64;
-    let server_seed = generate_seed(&env, 12345);
+    let server_seed = generate_seed(&env, SERVER_SEED);
     let server_seed_hash = hash_seed(&env, &server_seed);
-    let crash_multiplier = 500u64;
-    client.create_round(&round_id, &server_seed_hash);
+    client.create_round(&server_seed_hash);
 
-    let player = Address::generate(&env);
+    let player = create_player(&env, &client);
     let bet_amount = 100_000_000i128;
     let client_seed = generate_seed(&env, 111);
-    let bet_id = client.place_bet(&player, &round_id, &bet_amount, &client_seed);
+    let bet_id = client.place_bet(&player, &round_id, &bet_amount, &client_seed, &None);
 
-    client.start_round(&round_id, &server_seed, &crash_multiplier);
+    client.start_round(&round_id, &server_seed);
 
     // First cash out
-    client.cash_out(&player, &bet_id, &200);
+    advance_time(&env, 7);
+    client.cash_out(&player, &bet_id);
 
     // Try to cash out again - should error
-    let result = client.try_cash_out(&player, &bet_id, &300);
+    advance_time(&env, 5);
+    let result = client.try_cash_out(&player, &bet_id);
     assert_eq!(result.err(), Some(Ok(Error::BetNotActive)));
 }
 
@@ -331,10 +706,12 @@ fn test_finalize_round() {
     let (_admin, client) = create_test_contract(&env);
 
     let round_id = 1u64;
-    let server_seed = generate_seed(&env, 12345);
+    let server_seed = generate_seed(&env, SERVER_SEED);
     let server_seed_hash = hash_seed(&env, &server_seed);
-    client.create_round(&round_id, &server_seed_hash);
-    client.start_round(&round_id, &server_seed, &250);
+    client.create_round(&server_seed_hash);
+    client.start_round(&round_id, &server_seed);
+
+    advance_to_crash(&env, &client, round_id);
 
     client.finalize_round(&round_id);
 
@@ -343,32 +720,58 @@ fn test_finalize_round() {
     assert!(round.ended_at > 0);
 }
 
+#[test]
+fn test_finalize_round_before_crash() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    client.create_round(&hash_seed(&env, &server_seed));
+
+    let player = create_player(&env, &client);
+    let bet_id = client.place_bet(&player, &round_id, &100_000_000, &generate_seed(&env, 111), &None);
+    client.start_round(&round_id, &server_seed);
+
+    // Crash point is 1.60x, the curve is only at 1.50x
+    advance_time(&env, 7);
+    let result = client.try_finalize_round(&round_id);
+    assert_eq!(result.err(), Some(Ok(Error::RoundNotCrashed)));
+    assert_eq!(client.get_bet(&bet_id).status, BetStatus::Active);
+
+    // The player can still cash out, then the round settles after the crash
+    client.cash_out(&player, &bet_id);
+    advance_time(&env, 2);
+    client.finalize_round(&round_id);
+    assert_eq!(client.get_round(&round_id).status, RoundStatus::Ended);
+}
+
 #[test]
 fn test_multiple_bets_same_round() {
     let env = Env::default();
     let (_admin, client) = create_test_contract(&env);
 
     let round_id = 1u64;
-    let server_seed_hash = hash_seed(&env, &generate_seed(&env, 12345));
-    client.create_round(&round_id, &server_seed_hash);
+    let server_seed_hash = hash_seed(&env, &generate_seed(&env, SERVER_SEED));
+    client.create_round(&server_seed_hash);
 
     // Player 1 bets
-    let player1 = Address::generate(&env);
+    let player1 = create_player(&env, &client);
     let bet1_amount = 100_000_000i128;
     let client_seed1 = generate_seed(&env, 111);
-    client.place_bet(&player1, &round_id, &bet1_amount, &client_seed1);
+    client.place_bet(&player1, &round_id, &bet1_amount, &client_seed1, &None);
 
     // Player 2 bets
-    let player2 = Address::generate(&env);
+    let player2 = create_player(&env, &client);
     let bet2_amount = 200_000_000i128;
     let client_seed2 = generate_seed(&env, 222);
-    client.place_bet(&player2, &round_id, &bet2_amount, &client_seed2);
+    client.place_bet(&player2, &round_id, &bet2_amount, &client_seed2, &None);
 
     // Player 3 bets
-    let player3 = Address::generate(&env);
+    let player3 = create_player(&env, &client);
     let bet3_amount = 300_000_000i128;
     let client_seed3 = generate_seed(&env, 333);
-    client.place_bet(&player3, &round_id, &bet3_amount, &client_seed3);
+    client.place_bet(&player3, &round_id, &bet3_amount, &client_seed3, &None);
 
     let round = client.get_round(&round_id);
     assert_eq!(round.bet_count, 3);
@@ -385,15 +788,15 @@ fn test_client_seeds_collection() {
     let (_admin, client) = create_test_contract(&env);
 
     let round_id = 1u64;
-    let server_seed_hash = hash_seed(&env, &generate_seed(&env, 12345));
-    client.create_round(&round_id, &server_seed_hash);
+    let server_seed_hash = hash_seed(&env, &generate_seed(&env, SERVER_SEED));
+    client.create_round(&server_seed_hash);
 
     // Place 5 bets
     for i in 0..5 {
-        let player = Address::generate(&env);
+        let player = create_player(&env, &client);
         let bet_amount = 100_000_000i128;
         let client_seed = generate_seed(&env, i);
-        client.place_bet(&player, &round_id, &bet_amount, &client_seed);
+        client.place_bet(&player, &round_id, &bet_amount, &client_seed, &None);
     }
 
     let round = client.get_round(&round_id);
@@ -409,26 +812,2722 @@ fn test_payout_calculation_accuracy() {
     let (_admin, client) = create_test_contract(&env);
 
     let round_id = 1u64;
-    let server_seed = generate_seed(&env, 12345);
+    let server_seed = generate_seed(&env, HIGH_CRASH_SEED);
     let server_seed_hash = hash_seed(&env, &server_seed);
-    client.create_round(&round_id, &server_seed_hash);
+    client.create_round(&server_seed_hash);
 
-    let player = Address::generate(&env);
+    let player = create_player(&env, &client);
     let bet_amount = 500_000_000i128; // 50 XLM
     let client_seed = generate_seed(&env, 111);
-    let bet_id = client.place_bet(&player, &round_id, &bet_amount, &client_seed);
+    let bet_id = client.place_bet(&player, &round_id, &bet_amount, &client_seed, &None);
 
-    client.start_round(&round_id, &server_seed, &1000); // Crash at 10.00x
+    client.start_round(&round_id, &server_seed); // Crash at 9.70x
 
-    // Cash out at 3.50x
-    let payout = client.cash_out(&player, &bet_id, &350);
+    // Cash out after 22 seconds: 1.06^22 = 3.60x
+    advance_time(&env, 22);
+    let payout = client.cash_out(&player, &bet_id);
 
-    // Expected: 50 XLM * 3.50x = 175 XLM
-    // With 3% house edge: 175 * 0.97 = 169.75 XLM
-    let expected_payout = 1_697_500_000i128;
+    // Expected: 50 XLM * 3.60x = 180 XLM
+    // With 3% house edge: 180 * 0.97 = 174.6 XLM
+    let expected_payout = 1_746_000_000i128;
     assert_eq!(payout, expected_payout);
 
     let pool = client.get_pool();
-    assert_eq!(pool.total_house_earnings, 52_500_000i128); // 3% of 175 XLM
+    assert_eq!(pool.total_house_earnings, 54_000_000i128); // 3% of 180 XLM
+}
+
+
+#[test]
+fn test_place_bet_transfers_stake() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed_hash = hash_seed(&env, &generate_seed(&env, SERVER_SEED));
+    client.create_round(&server_seed_hash);
+
+    let player = create_player(&env, &client);
+    let bet_amount = 100_000_000i128;
+    client.place_bet(&player, &round_id, &bet_amount, &generate_seed(&env, 111), &None);
+
+    assert_eq!(balance(&env, &client, &player), PLAYER_BALANCE - bet_amount);
+    assert_eq!(balance(&env, &client, &client.address), HOUSE_BANKROLL + bet_amount);
+}
+
+#[test]
+fn test_place_bet_insufficient_balance() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed_hash = hash_seed(&env, &generate_seed(&env, SERVER_SEED));
+    client.create_round(&server_seed_hash);
+
+    // Player without any tokens
+    let player = Address::generate(&env);
+    let client_seed = generate_seed(&env, 111);
+    let result = client.try_place_bet(&player, &round_id, &100_000_000, &client_seed, &None);
+    assert_eq!(result.err(), Some(Ok(Error::TransferFailed)));
+
+    // Nothing recorded for the failed bet
+    let round = client.get_round(&round_id);
+    assert_eq!(round.bet_count, 0);
+    assert_eq!(client.get_pool().total_bets, 0);
+}
+
+#[test]
+fn test_cash_out_transfers_payout() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, HIGH_CRASH_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+
+    let player = create_player(&env, &client);
+    let bet_amount = 100_000_000i128;
+    let bet_id = client.place_bet(&player, &round_id, &bet_amount, &generate_seed(&env, 111), &None);
+
+    client.start_round(&round_id, &server_seed); // Crash at 9.70x
+    advance_time(&env, 12);
+    let payout = client.cash_out(&player, &bet_id);
+
+    assert_eq!(balance(&env, &client, &player), PLAYER_BALANCE - bet_amount + payout);
+    assert_eq!(
+        balance(&env, &client, &client.address),
+        HOUSE_BANKROLL + bet_amount - payout
+    );
+}
+
+#[test]
+fn test_multiplier_curve() {
+    assert_eq!(curve::multiplier_at(0), 100);
+    assert_eq!(curve::multiplier_at(1), 106);
+    assert_eq!(curve::multiplier_at(7), 150);
+    assert_eq!(curve::multiplier_at(12), 201);
+    assert_eq!(curve::multiplier_at(22), 360);
+
+    // Monotonic and saturating instead of overflowing
+    assert!(curve::multiplier_at(100) > curve::multiplier_at(99));
+    assert_eq!(curve::multiplier_at(u64::MAX), curve::multiplier_at(10_000));
+    assert_eq!(curve::multiplier_at(u64::MAX), curve::MAX_MULTIPLIER);
+
+    // First second at or above a multiplier
+    assert_eq!(curve::time_to_reach(100), 0);
+    assert_eq!(curve::time_to_reach(150), 7);
+    assert_eq!(curve::time_to_reach(151), 8);
+    assert_eq!(curve::time_to_reach(200), 12);
+    let to_cap = curve::time_to_reach(10_000);
+    assert!(curve::multiplier_at(to_cap) >= 10_000);
+    assert!(curve::multiplier_at(to_cap - 1) < 10_000);
+    let to_saturation = curve::time_to_reach(curve::MAX_MULTIPLIER);
+    assert_eq!(curve::multiplier_at(to_saturation), curve::MAX_MULTIPLIER);
+    assert_eq!(curve::time_to_reach(curve::MAX_MULTIPLIER + 1), u64::MAX);
+}
+
+#[test]
+fn test_cash_out_at_start_of_round() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+
+    let player = create_player(&env, &client);
+    let bet_amount = 100_000_000i128;
+    let bet_id = client.place_bet(&player, &round_id, &bet_amount, &generate_seed(&env, 111), &None);
+
+    client.start_round(&round_id, &server_seed);
+
+    // No time has passed: 1.00x, stake returned minus house edge
+    let payout = client.cash_out(&player, &bet_id);
+    assert_eq!(payout, 97_000_000i128);
+    assert_eq!(client.get_bet(&bet_id).cash_out_multiplier, 100);
+}
+
+#[test]
+fn test_get_multiplier_stops_at_crash() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+
+    // Not started yet
+    let result = client.try_get_multiplier(&round_id);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidRoundStatus)));
+
+    client.start_round(&round_id, &server_seed);
+    assert_eq!(client.get_multiplier(&round_id), 100);
+
+    advance_time(&env, 60);
+    let round = client.get_round(&round_id);
+    assert_eq!(client.get_multiplier(&round_id), round.crash_multiplier);
+}
+
+#[test]
+fn test_verify_round() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+
+    let player = create_player(&env, &client);
+    let client_seed = generate_seed(&env, 111);
+    client.place_bet(&player, &round_id, &100_000_000, &client_seed, &None);
+
+    // Nothing to verify before the server seed is revealed
+    let result = client.try_verify_round(&round_id);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidRoundStatus)));
+
+    client.start_round(&round_id, &server_seed);
+    advance_to_crash(&env, &client, round_id);
+    client.finalize_round(&round_id);
+
+    let verification = client.verify_round(&round_id);
+    assert_eq!(verification.round_id, round_id);
+    assert_eq!(verification.server_seed, server_seed);
+    assert_eq!(verification.server_seed_hash, server_seed_hash);
+    assert_eq!(verification.seed_chain, ChainSource::None);
+    assert_eq!(verification.client_seeds, vec![&env, client_seed]);
+    assert_eq!(verification.crash_multiplier, 160);
+    assert_eq!(verification.recorded_crash_multiplier, 160);
+    assert!(verification.seed_hash_valid);
+    assert!(verification.crash_multiplier_valid);
+    assert!(verification.verified);
+}
+
+#[test]
+fn test_verify_round_not_found() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let result = client.try_verify_round(&42);
+    assert_eq!(result.err(), Some(Ok(Error::RoundNotFound)));
+}
+
+#[test]
+fn test_round_and_bet_keys_do_not_collide() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed_hash = hash_seed(&env, &generate_seed(&env, SERVER_SEED));
+    client.create_round(&server_seed_hash);
+
+    let player = create_player(&env, &client);
+    let bet_id = client.place_bet(&player, &round_id, &100_000_000, &generate_seed(&env, 111), &None);
+
+    // A round sharing the bet's id is a different entry
+    let mut round = client.get_round(&round_id);
+    round.id = bet_id;
+    env.as_contract(&client.address, || storage::set_round(&env, bet_id, &round));
+    assert_eq!(client.get_bet(&bet_id).player, player);
+    assert_eq!(client.get_round(&bet_id).id, bet_id);
+}
+
+#[test]
+fn test_migrate_storage() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 7u64;
+    let bet_id = 99u64;
+    let server_seed_hash = hash_seed(&env, &generate_seed(&env, SERVER_SEED));
+    let player = Address::generate(&env);
+    let old_round = types::RoundV1 {
+        id: round_id,
+        status: RoundStatus::Ended,
+        server_seed_hash: server_seed_hash.clone(),
+        crash_multiplier: 120,
+        created_at: 0,
+        started_at: 10,
+        ended_at: 20,
+        total_bet_amount: 100_000_000,
+        total_payout: 0,
+        bet_count: 1,
+        client_seeds: soroban_sdk::Vec::new(&env),
+    };
+    let old_bet = types::BetV1 {
+        id: bet_id,
+        round_id,
+        player: player.clone(),
+        amount: 100_000_000,
+        cash_out_multiplier: 0,
+        payout: 0,
+        status: BetStatus::Lost,
+        timestamp: 0,
+    };
+
+    // Write entries the way older versions did: under bare u64 ids
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(&round_id, &old_round);
+        env.storage().persistent().set(&bet_id, &old_bet);
+    });
+    assert_eq!(client.try_get_round(&round_id).err(), Some(Ok(Error::RoundNotFound)));
+
+    // Unsettled legacy entries are refused, their stakes were never escrowed
+    let open_round = types::RoundV1 {
+        id: 8,
+        status: RoundStatus::Waiting,
+        ..old_round.clone()
+    };
+    let active_bet = types::BetV1 {
+        id: 100,
+        status: BetStatus::Active,
+        ..old_bet.clone()
+    };
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(&8u64, &open_round);
+        env.storage().persistent().set(&100u64, &active_bet);
+    });
+    let result = client.try_migrate_storage(&vec![&env, 8u64]);
+    assert_eq!(result.err(), Some(Ok(Error::UnsettledLegacyEntry)));
+    let result = client.try_migrate_storage(&vec![&env, 100u64]);
+    assert_eq!(result.err(), Some(Ok(Error::UnsettledLegacyEntry)));
+
+    let ids = vec![&env, round_id, bet_id, 12345u64];
+    assert_eq!(client.migrate_storage(&ids), 2);
+
+    // Moved entries are rewritten in the current layout
+    let config = client.get_config();
+    assert_eq!(
+        client.get_round(&round_id),
+        Round {
+            id: round_id,
+            status: RoundStatus::Ended,
+            server_seed_hash,
+            server_seed: None,
+            crash_multiplier: 120,
+            house_edge_bps: config.house_edge_bps,
+            max_multiplier: config.max_multiplier,
+            mix_ledger_entropy: config.mix_ledger_entropy,
+            waiting_timeout: config.waiting_timeout,
+            in_progress_timeout: config.in_progress_timeout,
+            created_at: 0,
+            started_at: 10,
+            ended_at: 20,
+            total_bet_amount: 100_000_000,
+            total_payout: 0,
+            bet_count: 1,
+            client_seeds: soroban_sdk::Vec::new(&env),
+            winner_count: 0,
+            loser_count: 0,
+            house_profit: 100_000_000,
+            committed_bets: 0,
+            reveal_ends_at: 0,
+        }
+    );
+    assert_eq!(
+        client.get_bet(&bet_id),
+        Bet {
+            id: bet_id,
+            round_id,
+            player,
+            amount: 100_000_000,
+            cash_out_multiplier: 0,
+            payout: 0,
+            status: BetStatus::Lost,
+            timestamp: 0,
+            auto_cash_out: None,
+            partial_cash_outs: soroban_sdk::Vec::new(&env),
+        }
+    );
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().persistent().has(&round_id));
+        assert!(!env.storage().persistent().has(&bet_id));
+    });
+
+    // Running it again is a no-op
+    assert_eq!(client.migrate_storage(&ids), 0);
+}
+
+#[test]
+fn test_finalize_round_settles_bets() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, HIGH_CRASH_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+
+    let winner = create_player(&env, &client);
+    let loser1 = create_player(&env, &client);
+    let loser2 = create_player(&env, &client);
+    let seed = generate_seed(&env, 111);
+    let winner_bet = client.place_bet(&winner, &round_id, &100_000_000, &seed, &None);
+    let seed = generate_seed(&env, 222);
+    let loser1_bet = client.place_bet(&loser1, &round_id, &200_000_000, &seed, &None);
+    let seed = generate_seed(&env, 333);
+    let loser2_bet = client.place_bet(&loser2, &round_id, &300_000_000, &seed, &None);
+
+    client.start_round(&round_id, &server_seed);
+    let round = client.get_round(&round_id);
+
+    // Cash out at 1.00x to stay below whatever the crash point is
+    let payout = client.cash_out(&winner, &winner_bet);
+    assert_eq!(payout, 97_000_000);
+
+    advance_time(&env, 10_000);
+    client.finalize_round(&round_id);
+
+    assert_eq!(client.get_bet(&winner_bet).status, BetStatus::CashedOut);
+    assert_eq!(client.get_bet(&loser1_bet).status, BetStatus::Lost);
+    assert_eq!(client.get_bet(&loser2_bet).status, BetStatus::Lost);
+
+    let settled = client.get_round(&round_id);
+    assert_eq!(settled.crash_multiplier, round.crash_multiplier);
+    assert_eq!(settled.total_payout, payout);
+    assert_eq!(settled.winner_count, 1);
+    assert_eq!(settled.loser_count, 2);
+    assert_eq!(settled.house_profit, 600_000_000 - payout);
 }
 
+#[test]
+fn test_auto_cash_out_settled_on_finalize() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+
+    // Round crashes at 2.00x: 1.50x target wins, 2.50x target loses
+    let winner = create_player(&env, &client);
+    let loser = create_player(&env, &client);
+    let bet_amount = 100_000_000i128;
+    let seed = generate_seed(&env, 111);
+    let winner_bet = client.place_bet(&winner, &round_id, &bet_amount, &seed, &Some(150));
+    let loser_bet = client.place_bet(&loser, &round_id, &bet_amount, &seed, &Some(250));
+    assert_eq!(client.get_bet(&winner_bet).auto_cash_out, Some(150));
+
+    client.start_round(&round_id, &server_seed);
+    assert_eq!(client.get_round(&round_id).crash_multiplier, 200);
+
+    advance_time(&env, 10_000);
+    client.finalize_round(&round_id);
+
+    // 10 XLM * 1.50x * 0.97 = 14.55 XLM
+    let bet = client.get_bet(&winner_bet);
+    assert_eq!(bet.status, BetStatus::CashedOut);
+    assert_eq!(bet.cash_out_multiplier, 150);
+    assert_eq!(bet.payout, 145_500_000);
+    assert_eq!(balance(&env, &client, &winner), PLAYER_BALANCE - bet_amount + bet.payout);
+
+    assert_eq!(client.get_bet(&loser_bet).status, BetStatus::Lost);
+    assert_eq!(balance(&env, &client, &loser), PLAYER_BALANCE - bet_amount);
+
+    let round = client.get_round(&round_id);
+    assert_eq!(round.winner_count, 1);
+    assert_eq!(round.loser_count, 1);
+    assert_eq!(round.total_payout, 145_500_000);
+    assert_eq!(client.get_pool().total_payouts, 145_500_000);
+}
+
+#[test]
+fn test_auto_cash_out_at_crash_point_wins() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+
+    let player = create_player(&env, &client);
+    let seed = generate_seed(&env, 111);
+    let bet_id = client.place_bet(&player, &round_id, &100_000_000, &seed, &Some(160));
+
+    client.start_round(&round_id, &server_seed);
+    advance_to_crash(&env, &client, round_id);
+    client.finalize_round(&round_id);
+
+    let bet = client.get_bet(&bet_id);
+    assert_eq!(bet.status, BetStatus::CashedOut);
+    assert_eq!(bet.cash_out_multiplier, 160);
+}
+
+#[test]
+fn test_manual_cash_out_capped_at_auto_target() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, HIGH_CRASH_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+
+    let player = create_player(&env, &client);
+    let seed = generate_seed(&env, 111);
+    let bet_id = client.place_bet(&player, &round_id, &100_000_000, &seed, &Some(150));
+
+    client.start_round(&round_id, &server_seed);
+
+    // Curve is at 2.01x but the bet already left at its 1.50x target
+    advance_time(&env, 12);
+    let payout = client.cash_out(&player, &bet_id);
+    assert_eq!(payout, 145_500_000);
+    assert_eq!(client.get_bet(&bet_id).cash_out_multiplier, 150);
+}
+
+#[test]
+fn test_place_bet_invalid_auto_cash_out() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed_hash = hash_seed(&env, &generate_seed(&env, SERVER_SEED));
+    client.create_round(&server_seed_hash);
+
+    let player = create_player(&env, &client);
+    let seed = generate_seed(&env, 111);
+    let result = client.try_place_bet(&player, &round_id, &100_000_000, &seed, &Some(100));
+    assert_eq!(result.err(), Some(Ok(Error::InvalidMultiplier)));
+}
+
+#[test]
+fn test_cancel_round_and_refund() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+
+    // Committed seed only, the crash point can't be known yet
+    let player = create_player(&env, &client);
+    let bet_amount = 100_000_000i128;
+    let commitment = hash_seed(&env, &generate_seed(&env, 111));
+    let bet_id = client.place_committed_bet(&player, &round_id, &bet_amount, &commitment, &None);
+
+    // Round still running normally: no refund
+    let result = client.try_claim_refund(&bet_id);
+    assert_eq!(result.err(), Some(Ok(Error::RefundNotAvailable)));
+
+    client.cancel_round(&round_id);
+    assert_eq!(client.get_round(&round_id).status, RoundStatus::Cancelled);
+
+    // Cancelled round can't be started anymore
+    let result = client.try_start_round(&round_id, &server_seed);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidRoundStatus)));
+
+    assert_eq!(client.claim_refund(&bet_id), bet_amount);
+    assert_eq!(client.get_bet(&bet_id).status, BetStatus::Refunded);
+    assert_eq!(balance(&env, &client, &player), PLAYER_BALANCE);
+    assert_eq!(client.get_pool().total_bets, 0);
+
+    // Only once
+    let result = client.try_claim_refund(&bet_id);
+    assert_eq!(result.err(), Some(Ok(Error::BetNotActive)));
+}
+
+#[test]
+fn test_cancel_round_with_client_seeds() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    // A plain seed lets the operator compute the crash point
+    let server_seed_hash = hash_seed(&env, &generate_seed(&env, SERVER_SEED));
+    client.create_round(&server_seed_hash);
+    let player = create_player(&env, &client);
+    let seed = generate_seed(&env, 111);
+    let bet_id = client.place_bet(&player, &1, &100_000_000, &seed, &None);
+    let result = client.try_cancel_round(&1);
+    assert_eq!(result.err(), Some(Ok(Error::ClientSeedsCollected)));
+
+    // So does a revealed one
+    let other = create_player(&env, &client);
+    let commitment = hash_seed(&env, &seed);
+    let committed_bet_id = client.place_committed_bet(&other, &1, &100_000_000, &commitment, &None);
+    client.close_betting(&1);
+    client.reveal_client_seed(&other, &committed_bet_id, &seed);
+    let result = client.try_cancel_round(&1);
+    assert_eq!(result.err(), Some(Ok(Error::ClientSeedsCollected)));
+
+    // Players get their stakes back through the waiting timeout
+    advance_time(&env, client.get_config().waiting_timeout);
+    assert_eq!(client.claim_refund(&bet_id), 100_000_000);
+    assert_eq!(client.claim_refund(&committed_bet_id), 100_000_000);
+
+    // With ledger entropy drawn at start, the seeds don't give it away
+    client.set_config(&Config {
+        mix_ledger_entropy: true,
+        ..client.get_config()
+    });
+    client.create_round(&server_seed_hash);
+    client.place_bet(&player, &2, &100_000_000, &seed, &None);
+    client.cancel_round(&2);
+    assert_eq!(client.get_round(&2).status, RoundStatus::Cancelled);
+}
+
+#[test]
+fn test_cancel_ended_round() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+    client.start_round(&round_id, &server_seed);
+    advance_to_crash(&env, &client, round_id);
+    client.finalize_round(&round_id);
+
+    let result = client.try_cancel_round(&round_id);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidRoundStatus)));
+}
+
+#[test]
+fn test_refund_after_waiting_timeout() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+
+    let player = create_player(&env, &client);
+    let bet_amount = 100_000_000i128;
+    let seed = generate_seed(&env, 111);
+    let bet_id = client.place_bet(&player, &round_id, &bet_amount, &seed, &None);
+
+    let waiting_timeout = client.get_config().waiting_timeout;
+    advance_time(&env, waiting_timeout - 1);
+    let result = client.try_claim_refund(&bet_id);
+    assert_eq!(result.err(), Some(Ok(Error::RefundNotAvailable)));
+
+    // Admin never started the round
+    advance_time(&env, 1);
+    assert_eq!(client.claim_refund(&bet_id), bet_amount);
+    assert_eq!(balance(&env, &client, &player), PLAYER_BALANCE);
+
+    // The stuck round is cancelled for good
+    assert_eq!(client.get_round(&round_id).status, RoundStatus::Cancelled);
+    let result = client.try_start_round(&round_id, &server_seed);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidRoundStatus)));
+}
+
+#[test]
+fn test_refund_timeouts_set_at_creation() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let server_seed = generate_seed(&env, HIGH_CRASH_SEED);
+    let round = client.create_round(&hash_seed(&env, &server_seed));
+    let config = client.get_config();
+    assert_eq!(round.waiting_timeout, config.waiting_timeout);
+    assert_eq!(round.in_progress_timeout, config.in_progress_timeout);
+
+    let player = create_player(&env, &client);
+    let bet_amount = 100_000_000i128;
+    let bet_id = client.place_bet(&player, &round.id, &bet_amount, &generate_seed(&env, 111), &None);
+
+    // Shorter timeouts don't make the open round refundable early
+    client.set_config(&Config {
+        waiting_timeout: 600,
+        in_progress_timeout: 600,
+        ..config.clone()
+    });
+    advance_time(&env, 600);
+    let result = client.try_claim_refund(&bet_id);
+    assert_eq!(result.err(), Some(Ok(Error::RefundNotAvailable)));
+
+    client.start_round(&round.id, &server_seed);
+    advance_time(&env, 600);
+    let result = client.try_claim_refund(&bet_id);
+    assert_eq!(result.err(), Some(Ok(Error::RefundNotAvailable)));
+
+    // Longer ones don't keep players waiting past the round's own
+    client.set_config(&Config {
+        in_progress_timeout: 2 * config.in_progress_timeout,
+        ..config.clone()
+    });
+    advance_time(&env, config.in_progress_timeout - 600);
+    assert_eq!(client.claim_refund(&bet_id), bet_amount);
+}
+
+#[test]
+fn test_refund_after_in_progress_timeout() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, HIGH_CRASH_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+
+    let player = create_player(&env, &client);
+    let other = create_player(&env, &client);
+    let bet_amount = 100_000_000i128;
+    let seed = generate_seed(&env, 111);
+    let bet_id = client.place_bet(&player, &round_id, &bet_amount, &seed, &None);
+    let other_bet = client.place_bet(&other, &round_id, &bet_amount, &seed, &None);
+
+    client.start_round(&round_id, &server_seed);
+    let payout = client.cash_out(&other, &other_bet);
+
+    // Admin never finalizes the round
+    advance_time(&env, client.get_config().in_progress_timeout);
+    assert_eq!(client.claim_refund(&bet_id), bet_amount);
+    assert_eq!(balance(&env, &client, &player), PLAYER_BALANCE);
+
+    // Already settled bets keep their payout
+    let result = client.try_claim_refund(&other_bet);
+    assert_eq!(result.err(), Some(Ok(Error::BetNotActive)));
+    assert_eq!(balance(&env, &client, &other), PLAYER_BALANCE - bet_amount + payout);
+
+    let result = client.try_finalize_round(&round_id);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidRoundStatus)));
+}
+
+#[test]
+fn test_refund_after_partial_cash_out() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    client.create_round(&hash_seed(&env, &server_seed));
+
+    let player = create_player(&env, &client);
+    let bet_amount = 100_000_000i128;
+    let bet_id = client.place_bet(&player, &round_id, &bet_amount, &generate_seed(&env, 111), &None);
+    client.start_round(&round_id, &server_seed);
+
+    advance_time(&env, 7);
+    let payout = client.cash_out_partial(&player, &bet_id, &5_000);
+
+    // The operator can't void a round in progress, only the timeout can
+    let result = client.try_cancel_round(&round_id);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidRoundStatus)));
+    advance_time(&env, client.get_config().in_progress_timeout);
+
+    // Only the half still riding comes back
+    let refund = bet_amount / 2;
+    assert_eq!(client.claim_refund(&bet_id), refund);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![
+            &env,
+            to_event(&env, &RoundCancelled { round_id }),
+            to_event(
+                &env,
+                &RefundClaimed {
+                    round_id,
+                    player: player.clone(),
+                    bet_id,
+                    amount: refund,
+                }
+            ),
+        ]
+    );
+    assert_eq!(balance(&env, &client, &player), PLAYER_BALANCE - bet_amount + payout + refund);
+    assert_eq!(client.get_pool().total_bets, bet_amount - refund);
+    assert_eq!(client.get_player_stats(&player).net_pnl, payout + refund - bet_amount);
+}
+
+#[test]
+fn test_default_config() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let config = client.get_config();
+    assert_eq!(config.house_edge_bps, 300);
+    assert_eq!(config.min_bet, 10_000_000);
+    assert_eq!(config.max_bet, 1_000_000_000_000);
+    assert_eq!(config.max_multiplier, 10_000);
+    assert_eq!(config.max_client_seeds, 3);
+    assert_eq!(config.waiting_timeout, 3_600);
+    assert_eq!(config.in_progress_timeout, 3_600);
+    assert_eq!(config.max_exposure_bps, 1_000);
+}
+
+#[test]
+fn test_set_config() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let config = Config {
+        house_edge_bps: 100,
+        min_bet: 50_000_000,
+        max_bet: 500_000_000,
+        max_multiplier: 5_000,
+        max_client_seeds: 1,
+        waiting_timeout: 600,
+        in_progress_timeout: 600,
+        max_exposure_bps: 500,
+        unrevealed_penalty_bps: 2_000,
+        mix_ledger_entropy: false,
+        max_bets_per_player: 3,
+        max_bets_per_round: 10,
+        min_reveal_window: 30,
+    };
+    client.set_config(&config);
+    assert_eq!(client.get_config(), config);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, HIGH_CRASH_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+
+    let player = create_player(&env, &client);
+    let seed = generate_seed(&env, 111);
+    let result = client.try_place_bet(&player, &round_id, &10_000_000, &seed, &None);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidBetAmount)));
+    let result = client.try_place_bet(&player, &round_id, &600_000_000, &seed, &None);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidBetAmount)));
+
+    let bet_id = client.place_bet(&player, &round_id, &100_000_000, &seed, &None);
+    let other = create_player(&env, &client);
+    client.place_bet(&other, &round_id, &100_000_000, &generate_seed(&env, 222), &None);
+    assert_eq!(client.get_round(&round_id).client_seeds.len(), 1);
+
+    // 10 XLM * 2.01x with a 1% edge
+    client.start_round(&round_id, &server_seed);
+    advance_time(&env, 12);
+    assert_eq!(client.cash_out(&player, &bet_id), 198_990_000);
+}
+
+#[test]
+fn test_set_config_keeps_round_parameters() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, HIGH_CRASH_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+
+    let player = create_player(&env, &client);
+    let seed = generate_seed(&env, 111);
+    let bet_id = client.place_bet(&player, &round_id, &100_000_000, &seed, &None);
+
+    let mut config = client.get_config();
+    config.house_edge_bps = 1_000;
+    config.max_multiplier = 150;
+    client.set_config(&config);
+
+    // Round still uses the 3% edge and 100.00x cap it was created with
+    client.start_round(&round_id, &server_seed);
+    let round = client.get_round(&round_id);
+    assert_eq!(round.house_edge_bps, 300);
+    assert_eq!(round.crash_multiplier, 970);
+    advance_time(&env, 12);
+    assert_eq!(client.cash_out(&player, &bet_id), 194_970_000);
+    assert!(client.verify_round(&round_id).verified);
+}
+
+#[test]
+fn test_set_config_validation() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let valid = client.get_config();
+    let invalid = [
+        Config { house_edge_bps: 10_000, ..valid.clone() },
+        Config { min_bet: 0, ..valid.clone() },
+        Config { min_bet: valid.max_bet + 1, ..valid.clone() },
+        Config { max_multiplier: 100, ..valid.clone() },
+        Config { max_multiplier: curve::MAX_MULTIPLIER + 1, ..valid.clone() },
+        Config { max_client_seeds: 0, ..valid.clone() },
+        Config { waiting_timeout: 0, ..valid.clone() },
+        Config { in_progress_timeout: 0, ..valid.clone() },
+        // Rounds could be refunded before the curve reaches the cap
+        Config {
+            in_progress_timeout: curve::time_to_reach(valid.max_multiplier),
+            ..valid.clone()
+        },
+        Config {
+            max_multiplier: curve::MAX_MULTIPLIER,
+            in_progress_timeout: curve::time_to_reach(curve::MAX_MULTIPLIER),
+            ..valid.clone()
+        },
+        Config { max_exposure_bps: 0, ..valid.clone() },
+        Config { max_exposure_bps: 10_001, ..valid.clone() },
+        Config { unrevealed_penalty_bps: 10_001, ..valid.clone() },
+        Config { max_bets_per_player: 0, ..valid.clone() },
+        Config { max_bets_per_round: 0, ..valid.clone() },
+        Config { max_bets_per_round: storage::MAX_BETS_PER_ROUND + 1, ..valid.clone() },
+    ];
+    for config in invalid.iter() {
+        let result = client.try_set_config(config);
+        assert_eq!(result.err(), Some(Ok(Error::InvalidConfig)));
+    }
+    assert_eq!(client.get_config(), valid);
+
+    // Bounds themselves are accepted
+    let bounds = Config {
+        max_multiplier: curve::MAX_MULTIPLIER,
+        in_progress_timeout: curve::time_to_reach(curve::MAX_MULTIPLIER) + 1,
+        max_bets_per_round: storage::MAX_BETS_PER_ROUND,
+        ..valid
+    };
+    client.set_config(&bounds);
+    assert_eq!(client.get_config(), bounds);
+}
+
+#[test]
+fn test_round_lifecycle_events() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, HIGH_CRASH_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![
+            &env,
+            to_event(
+                &env,
+                &RoundCreated {
+                    round_id,
+                    server_seed_hash: server_seed_hash.clone(),
+                }
+            ),
+        ]
+    );
+
+    let winner = create_player(&env, &client);
+    let seed = generate_seed(&env, 111);
+    let winner_bet = client.place_bet(&winner, &round_id, &100_000_000, &seed, &None);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![
+            &env,
+            to_event(
+                &env,
+                &BetPlaced {
+                    round_id,
+                    player: winner.clone(),
+                    bet_id: winner_bet,
+                    amount: 100_000_000,
+                    auto_cash_out: None,
+                }
+            ),
+        ]
+    );
+
+    let loser = create_player(&env, &client);
+    client.place_bet(&loser, &round_id, &100_000_000, &seed, &Some(9_000));
+
+    client.start_round(&round_id, &server_seed);
+    let events = contract_events(&env, &client);
+    let crash_multiplier = client.get_round(&round_id).crash_multiplier;
+    assert_eq!(
+        events,
+        vec![
+            &env,
+            to_event(
+                &env,
+                &RoundStarted {
+                    round_id,
+                    server_seed: server_seed.clone(),
+                    crash_multiplier,
+                }
+            ),
+        ]
+    );
+
+    advance_time(&env, 12);
+    let payout = client.cash_out(&winner, &winner_bet);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![
+            &env,
+            to_event(
+                &env,
+                &CashedOut {
+                    round_id,
+                    player: winner.clone(),
+                    bet_id: winner_bet,
+                    multiplier: 201,
+                    payout,
+                }
+            ),
+        ]
+    );
+
+    advance_to_crash(&env, &client, round_id);
+
+    client.finalize_round(&round_id);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![
+            &env,
+            to_event(
+                &env,
+                &RoundFinalized {
+                    round_id,
+                    crash_multiplier,
+                    total_payout: payout,
+                    winner_count: 1,
+                    loser_count: 1,
+                    house_profit: 200_000_000 - payout,
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_auto_cash_out_event_on_finalize() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+
+    let player = create_player(&env, &client);
+    let seed = generate_seed(&env, 111);
+    let bet_id = client.place_bet(&player, &round_id, &100_000_000, &seed, &Some(150));
+
+    client.start_round(&round_id, &server_seed);
+    advance_to_crash(&env, &client, round_id);
+    client.finalize_round(&round_id);
+
+    let events = contract_events(&env, &client);
+    assert_eq!(events.len(), 2);
+    assert_eq!(
+        events.slice(0..1),
+        vec![
+            &env,
+            to_event(
+                &env,
+                &CashedOut {
+                    round_id,
+                    player,
+                    bet_id,
+                    multiplier: 150,
+                    payout: 145_500_000,
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_finalize_credits_failed_auto_cash_out() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    client.create_round(&hash_seed(&env, &server_seed));
+
+    // Round crashes at 1.60x, both auto cash-outs at 1.50x win
+    let seed = generate_seed(&env, 111);
+    let frozen = create_player(&env, &client);
+    let frozen_bet = client.place_bet(&frozen, &round_id, &100_000_000, &seed, &Some(150));
+    let player = create_player(&env, &client);
+    client.place_bet(&player, &round_id, &100_000_000, &seed, &Some(150));
+    client.start_round(&round_id, &server_seed);
+
+    // One winner can't receive tokens anymore
+    let token = StellarAssetClient::new(&env, &client.token());
+    token.set_authorized(&frozen, &false);
+
+    advance_to_crash(&env, &client, round_id);
+    client.finalize_round(&round_id);
+    let round = client.get_round(&round_id);
+    assert_eq!(round.status, RoundStatus::Ended);
+    assert_eq!(round.winner_count, 2);
+    assert_eq!(round.total_payout, 2 * 145_500_000);
+
+    // The other winner is paid, the frozen one is credited
+    assert_eq!(balance(&env, &client, &player), PLAYER_BALANCE + 45_500_000);
+    assert_eq!(client.get_unpaid_payout(&player), 0);
+    assert_eq!(client.get_bet(&frozen_bet).status, BetStatus::CashedOut);
+    assert_eq!(balance(&env, &client, &frozen), PLAYER_BALANCE - 100_000_000);
+    assert_eq!(client.get_unpaid_payout(&frozen), 145_500_000);
+
+    // Collected once the balance is unfrozen
+    token.set_authorized(&frozen, &true);
+    assert_eq!(client.claim_payout(&frozen), 145_500_000);
+    assert_eq!(balance(&env, &client, &frozen), PLAYER_BALANCE + 45_500_000);
+    assert_eq!(client.get_unpaid_payout(&frozen), 0);
+}
+
+#[test]
+fn test_cancel_and_refund_events() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed_hash = hash_seed(&env, &generate_seed(&env, SERVER_SEED));
+    client.create_round(&server_seed_hash);
+
+    let player = create_player(&env, &client);
+    let commitment = hash_seed(&env, &generate_seed(&env, 111));
+    let bet_id = client.place_committed_bet(&player, &round_id, &100_000_000, &commitment, &None);
+
+    client.cancel_round(&round_id);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![&env, to_event(&env, &RoundCancelled { round_id })]
+    );
+
+    client.claim_refund(&bet_id);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![
+            &env,
+            to_event(
+                &env,
+                &RefundClaimed {
+                    round_id,
+                    player,
+                    bet_id,
+                    amount: 100_000_000,
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_refund_after_timeout_events() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed_hash = hash_seed(&env, &generate_seed(&env, SERVER_SEED));
+    client.create_round(&server_seed_hash);
+
+    let player = create_player(&env, &client);
+    let seed = generate_seed(&env, 111);
+    let bet_id = client.place_bet(&player, &round_id, &100_000_000, &seed, &None);
+
+    advance_time(&env, client.get_config().waiting_timeout);
+    client.claim_refund(&bet_id);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![
+            &env,
+            to_event(&env, &RoundCancelled { round_id }),
+            to_event(
+                &env,
+                &RefundClaimed {
+                    round_id,
+                    player,
+                    bet_id,
+                    amount: 100_000_000,
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_config_updated_event() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let mut config = client.get_config();
+    config.house_edge_bps = 200;
+    client.set_config(&config);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![&env, to_event(&env, &ConfigUpdated { config })]
+    );
+}
+
+#[test]
+fn test_bankroll_tracks_round_results() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, HIGH_CRASH_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+
+    let winner = create_player(&env, &client);
+    let loser = create_player(&env, &client);
+    let seed = generate_seed(&env, 111);
+    let winner_bet = client.place_bet(&winner, &round_id, &100_000_000, &seed, &None);
+    client.place_bet(&loser, &round_id, &300_000_000, &seed, &None);
+
+    // Stakes are escrowed, not part of the bankroll
+    assert_eq!(client.get_pool().bankroll, HOUSE_BANKROLL);
+
+    client.start_round(&round_id, &server_seed);
+    advance_time(&env, 12);
+    let payout = client.cash_out(&winner, &winner_bet);
+    assert_eq!(client.get_pool().bankroll, HOUSE_BANKROLL - (payout - 100_000_000));
+
+    advance_to_crash(&env, &client, round_id);
+
+    client.finalize_round(&round_id);
+    let bankroll = HOUSE_BANKROLL - (payout - 100_000_000) + 300_000_000;
+    assert_eq!(client.get_pool().bankroll, bankroll);
+
+    // Bankroll plus nothing left in escrow is exactly what the contract holds
+    assert_eq!(balance(&env, &client, &client.address), bankroll);
+}
+
+#[test]
+fn test_place_bet_insufficient_bankroll() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    // 10% of 1,000,000 XLM = 100,000 XLM of worst-case payout: at 100.00x
+    // with a 3% edge that is about 1,030 XLM of stakes per round
+    let round_id = 1u64;
+    let server_seed_hash = hash_seed(&env, &generate_seed(&env, SERVER_SEED));
+    client.create_round(&server_seed_hash);
+
+    let seed = generate_seed(&env, 111);
+    let player1 = create_player(&env, &client);
+    client.place_bet(&player1, &round_id, &PLAYER_BALANCE, &seed, &None);
+
+    let player2 = create_player(&env, &client);
+    let result = client.try_place_bet(&player2, &round_id, &400_000_000, &seed, &None);
+    assert_eq!(result.err(), Some(Ok(Error::InsufficientBankroll)));
+
+    // Smaller bet still fits
+    client.place_bet(&player2, &round_id, &300_000_000, &seed, &None);
+}
+
+#[test]
+fn test_deposit_liquidity() {
+    let env = Env::default();
+    let (admin, client) = create_test_contract(&env);
+
+    // House seeded the bankroll at 1.0 per share
+    assert_eq!(client.get_lp_shares(&admin), HOUSE_BANKROLL);
+    assert_eq!(client.get_share_price(), 10_000_000);
+
+    let provider = create_player(&env, &client);
+    let shares = client.deposit_liquidity(&provider, &PLAYER_BALANCE);
+    assert_eq!(shares, PLAYER_BALANCE);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![
+            &env,
+            to_event(
+                &env,
+                &LiquidityDeposited {
+                    provider: provider.clone(),
+                    amount: PLAYER_BALANCE,
+                    shares,
+                }
+            ),
+        ]
+    );
+
+    let pool = client.get_pool();
+    assert_eq!(pool.bankroll, HOUSE_BANKROLL + PLAYER_BALANCE);
+    assert_eq!(pool.total_shares, HOUSE_BANKROLL + PLAYER_BALANCE);
+    assert_eq!(balance(&env, &client, &provider), 0);
+
+    let result = client.try_deposit_liquidity(&provider, &0);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidAmount)));
+}
+
+#[test]
+fn test_lp_shares_accrue_house_profit() {
+    let env = Env::default();
+    let (admin, client) = create_test_contract(&env);
+
+    // House wins a 1,000 XLM stake
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+    let player = create_player(&env, &client);
+    let seed = generate_seed(&env, 111);
+    client.place_bet(&player, &round_id, &PLAYER_BALANCE, &seed, &None);
+    client.start_round(&round_id, &server_seed);
+    advance_to_crash(&env, &client, round_id);
+    client.finalize_round(&round_id);
+
+    // 1,001,000 XLM for 1,000,000 shares
+    assert_eq!(client.get_share_price(), 10_010_000);
+
+    // New provider buys in at the higher price
+    let provider = create_player(&env, &client);
+    let shares = client.deposit_liquidity(&provider, &PLAYER_BALANCE);
+    assert_eq!(shares, PLAYER_BALANCE * 1_000 / 1_001);
+
+    // House takes its share of the profit
+    let amount = client.withdraw_liquidity(&admin, &HOUSE_BANKROLL);
+    assert_eq!(amount, HOUSE_BANKROLL + PLAYER_BALANCE);
+    assert_eq!(balance(&env, &client, &admin), HOUSE_BANKROLL + PLAYER_BALANCE);
+    assert_eq!(client.get_lp_shares(&admin), 0);
+
+    // Last provider gets what they put in, give or take rounding
+    let amount = client.withdraw_liquidity(&provider, &shares);
+    assert!(PLAYER_BALANCE - amount < 10);
+    assert_eq!(client.get_pool().total_shares, 0);
+}
+
+#[test]
+fn test_withdraw_liquidity_insufficient_shares() {
+    let env = Env::default();
+    let (admin, client) = create_test_contract(&env);
+
+    let result = client.try_withdraw_liquidity(&admin, &(HOUSE_BANKROLL + 1));
+    assert_eq!(result.err(), Some(Ok(Error::InsufficientShares)));
+
+    let result = client.try_withdraw_liquidity(&admin, &0);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidAmount)));
+}
+
+#[test]
+fn test_liquidity_locked_while_rounds_open() {
+    let env = Env::default();
+    let (admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, HIGH_CRASH_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+
+    // Deposits are refused until the round closes
+    let provider = create_player(&env, &client);
+    let result = client.try_deposit_liquidity(&provider, &PLAYER_BALANCE);
+    assert_eq!(result.err(), Some(Ok(Error::LiquidityLocked)));
+
+    // Withdrawals are queued
+    let shares = HOUSE_BANKROLL / 2;
+    assert_eq!(client.withdraw_liquidity(&admin, &shares), 0);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![
+            &env,
+            to_event(
+                &env,
+                &WithdrawalQueued {
+                    provider: admin.clone(),
+                    shares,
+                }
+            ),
+        ]
+    );
+    assert_eq!(client.get_lp_shares(&admin), HOUSE_BANKROLL - shares);
+    assert_eq!(client.get_pending_withdrawal(&admin), shares);
+    assert_eq!(balance(&env, &client, &admin), 0);
+
+    // Queued shares can't be withdrawn twice
+    let result = client.try_withdraw_liquidity(&admin, &HOUSE_BANKROLL);
+    assert_eq!(result.err(), Some(Ok(Error::InsufficientShares)));
+
+    // Player wins, the house loses some bankroll before the queue is paid
+    let player = create_player(&env, &client);
+    let seed = generate_seed(&env, 111);
+    let bet_id = client.place_bet(&player, &round_id, &100_000_000, &seed, &None);
+    client.start_round(&round_id, &server_seed);
+    advance_time(&env, 12);
+    let payout = client.cash_out(&player, &bet_id);
+    let loss = payout - 100_000_000;
+
+    advance_to_crash(&env, &client, round_id);
+
+    client.finalize_round(&round_id);
+    let amount = (HOUSE_BANKROLL - loss) / 2;
+    assert_eq!(client.get_pending_withdrawal(&admin), 0);
+    assert_eq!(client.get_claimable_withdrawal(&admin), amount);
+    assert_eq!(balance(&env, &client, &admin), 0);
+
+    // The provider pulls the settled amount, burning their queued shares
+    assert_eq!(client.claim_withdrawal(&admin), amount);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![
+            &env,
+            to_event(
+                &env,
+                &LiquidityWithdrawn {
+                    provider: admin.clone(),
+                    shares,
+                    amount,
+                }
+            ),
+            to_event(
+                &env,
+                &WithdrawalClaimed {
+                    provider: admin.clone(),
+                    amount,
+                }
+            ),
+        ]
+    );
+    assert_eq!(balance(&env, &client, &admin), amount);
+    assert_eq!(client.get_claimable_withdrawal(&admin), 0);
+    let result = client.try_claim_withdrawal(&admin);
+    assert_eq!(result.err(), Some(Ok(Error::NothingToClaim)));
+
+    let pool = client.get_pool();
+    assert_eq!(pool.total_shares, HOUSE_BANKROLL - shares);
+    assert_eq!(pool.bankroll, HOUSE_BANKROLL - loss - amount);
+
+    // Round closed, deposits are accepted again
+    client.deposit_liquidity(&provider, &PLAYER_BALANCE);
+}
+
+#[test]
+fn test_many_queued_withdrawals() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let mut providers = Vec::new(&env);
+    for _ in 0..200 {
+        let provider = create_player(&env, &client);
+        client.deposit_liquidity(&provider, &PLAYER_BALANCE);
+        providers.push_back(provider);
+    }
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    client.create_round(&hash_seed(&env, &server_seed));
+
+    // Every provider queues a withdrawal while the round is open
+    for provider in providers.iter() {
+        assert_eq!(client.withdraw_liquidity(&provider, &PLAYER_BALANCE), 0);
+    }
+
+    // The player loses, the house keeps the stake
+    let player = create_player(&env, &client);
+    let seed = generate_seed(&env, 111);
+    client.place_bet(&player, &round_id, &100_000_000, &seed, &None);
+    client.start_round(&round_id, &server_seed);
+    advance_to_crash(&env, &client, round_id);
+
+    // Closing the round doesn't touch the queued providers
+    client.finalize_round(&round_id);
+    assert_eq!(client.get_round(&round_id).status, RoundStatus::Ended);
+
+    let queued = PLAYER_BALANCE * 200;
+    let total_shares = HOUSE_BANKROLL + queued;
+    let settled = queued * (total_shares + 100_000_000) / total_shares;
+    let amount = PLAYER_BALANCE * settled / queued;
+    assert!(amount > PLAYER_BALANCE);
+
+    let pool = client.get_pool();
+    assert_eq!(pool.total_shares, HOUSE_BANKROLL);
+    assert_eq!(pool.bankroll, total_shares + 100_000_000 - settled);
+
+    // Each provider burns their shares at the settled price when claiming
+    for provider in providers.iter() {
+        assert_eq!(client.get_pending_withdrawal(&provider), 0);
+        assert_eq!(client.get_claimable_withdrawal(&provider), amount);
+    }
+    let first = providers.get(0).unwrap();
+    assert_eq!(client.claim_withdrawal(&first), amount);
+    assert_eq!(balance(&env, &client, &first), amount);
+    let last = providers.get(199).unwrap();
+    assert_eq!(client.claim_withdrawal(&last), amount);
+    assert_eq!(balance(&env, &client, &last), amount);
+}
+
+#[test]
+fn test_migrate_withdrawal_queue() {
+    let env = Env::default();
+    let (admin, client) = create_test_contract(&env);
+
+    // Schema 8 kept queued providers in an instance queue, with a bare
+    // share count under their pending withdrawal key
+    let shares = HOUSE_BANKROLL / 4;
+    env.as_contract(&client.address, || {
+        let mut queue = Vec::new(&env);
+        queue.push_back(admin.clone());
+        env.storage().instance().set(&storage::WITHDRAWALS, &queue);
+        env.storage()
+            .persistent()
+            .set(&storage::DataKey::PendingWithdrawal(admin.clone()), &shares);
+        env.storage()
+            .persistent()
+            .set(&storage::DataKey::LpShares(admin.clone()), &(HOUSE_BANKROLL - shares));
+    });
+
+    client.migrate(&Vec::new(&env), &None);
+    assert_eq!(client.get_pending_withdrawal(&admin), shares);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().instance().has(&storage::WITHDRAWALS));
+    });
+
+    // Burned with the first epoch once the open round is cancelled
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    client.create_round(&hash_seed(&env, &server_seed));
+    client.cancel_round(&1);
+    assert_eq!(client.claim_withdrawal(&admin), shares);
+}
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let env = Env::default();
+    let (admin, client) = create_test_contract(&env);
+
+    let new_admin = Address::generate(&env);
+    client.propose_admin(&new_admin);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![
+            &env,
+            to_event(
+                &env,
+                &AdminProposed {
+                    current_admin: admin.clone(),
+                    new_admin: new_admin.clone(),
+                }
+            ),
+        ]
+    );
+
+    // Nothing changes until the new admin accepts
+    assert_eq!(client.admin(), admin);
+    assert_eq!(client.pending_admin(), Some(new_admin.clone()));
+
+    client.accept_admin();
+    assert_eq!(env.auths()[0].0, new_admin);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![
+            &env,
+            to_event(
+                &env,
+                &AdminTransferred {
+                    previous_admin: admin.clone(),
+                    new_admin: new_admin.clone(),
+                }
+            ),
+        ]
+    );
+    assert_eq!(client.admin(), new_admin);
+    assert_eq!(client.pending_admin(), None);
+
+    // Roles are not transferred along with the admin
+    assert_eq!(client.get_role(&Role::Operator), admin);
+}
+
+#[test]
+fn test_accept_admin_without_proposal() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let result = client.try_accept_admin();
+    assert_eq!(result.err(), Some(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_accept_admin_requires_new_admin_auth() {
+    let env = Env::default();
+    let (admin, client) = create_test_contract(&env);
+
+    let new_admin = Address::generate(&env);
+    client.propose_admin(&new_admin);
+
+    // Current admin can't accept on behalf of the new one
+    env.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "accept_admin",
+            args: ().into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_accept_admin().is_err());
+    assert_eq!(client.admin(), admin);
+}
+
+#[test]
+fn test_roles() {
+    let env = Env::default();
+    let (admin, client) = create_test_contract(&env);
+
+    // Admin holds every role after deployment
+    assert_eq!(client.get_role(&Role::Operator), admin);
+    assert_eq!(client.get_role(&Role::Treasurer), admin);
+    assert_eq!(client.get_role(&Role::Pauser), admin);
+
+    let operator = Address::generate(&env);
+    let treasurer = Address::generate(&env);
+    client.set_role(&Role::Operator, &operator);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![
+            &env,
+            to_event(
+                &env,
+                &RoleSet {
+                    account: operator.clone(),
+                    role: Role::Operator,
+                }
+            ),
+        ]
+    );
+    client.set_role(&Role::Treasurer, &treasurer);
+    assert_eq!(env.auths()[0].0, admin);
+
+    // Operator runs the rounds
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    client.create_round(&hash_seed(&env, &server_seed));
+    assert_eq!(env.auths()[0].0, operator);
+    client.start_round(&round_id, &server_seed);
+    assert_eq!(env.auths()[0].0, operator);
+    advance_to_crash(&env, &client, round_id);
+    client.finalize_round(&round_id);
+    assert_eq!(env.auths()[0].0, operator);
+    client.create_round(&hash_seed(&env, &server_seed));
+    client.cancel_round(&2);
+    assert_eq!(env.auths()[0].0, operator);
+
+    // Treasurer sets the economics
+    client.set_config(&client.get_config());
+    assert_eq!(env.auths()[0].0, treasurer);
+}
+
+#[test]
+fn test_admin_cannot_act_as_operator() {
+    let env = Env::default();
+    let (admin, client) = create_test_contract(&env);
+
+    let operator = Address::generate(&env);
+    client.set_role(&Role::Operator, &operator);
+
+    let server_seed_hash = hash_seed(&env, &generate_seed(&env, SERVER_SEED));
+    env.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "create_round",
+            args: (server_seed_hash.clone(),).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_create_round(&server_seed_hash).is_err());
+}
+
+#[test]
+fn test_pause_and_unpause() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let pauser = Address::generate(&env);
+    client.set_role(&Role::Pauser, &pauser);
+    assert!(!client.paused());
+
+    client.pause();
+    assert_eq!(env.auths()[0].0, pauser);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![&env, to_event(&env, &Paused { pauser: pauser.clone() })]
+    );
+    assert!(client.paused());
+    assert_eq!(client.try_pause().err(), Some(Ok(Error::ContractPaused)));
+
+    client.unpause();
+    assert_eq!(env.auths()[0].0, pauser);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![&env, to_event(&env, &Unpaused { pauser: pauser.clone() })]
+    );
+    assert!(!client.paused());
+    assert_eq!(client.try_unpause().err(), Some(Ok(Error::ContractNotPaused)));
+}
+
+#[test]
+fn test_paused_blocks_new_rounds_and_bets() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    let player = create_player(&env, &client);
+    let bet_amount = 100_000_000i128;
+    let seed = generate_seed(&env, 111);
+
+    client.pause();
+    let result = client.try_create_round(&server_seed_hash);
+    assert_eq!(result.err(), Some(Ok(Error::ContractPaused)));
+    client.commit_seed_chain(&server_seed_hash, &1);
+    let result = client.try_create_chained_round();
+    assert_eq!(result.err(), Some(Ok(Error::ContractPaused)));
+
+    // No new liquidity either, even with no round open
+    let provider = create_player(&env, &client);
+    let result = client.try_deposit_liquidity(&provider, &PLAYER_BALANCE);
+    assert_eq!(result.err(), Some(Ok(Error::ContractPaused)));
+    assert_eq!(balance(&env, &client, &provider), PLAYER_BALANCE);
+
+    client.unpause();
+    client.create_chained_round();
+
+    client.pause();
+    let result = client.try_place_bet(&player, &round_id, &bet_amount, &seed, &None);
+    assert_eq!(result.err(), Some(Ok(Error::ContractPaused)));
+    let committer = create_player(&env, &client);
+    let commitment = hash_seed(&env, &generate_seed(&env, 222));
+    let result =
+        client.try_place_committed_bet(&committer, &round_id, &bet_amount, &commitment, &None);
+    assert_eq!(result.err(), Some(Ok(Error::ContractPaused)));
+    assert_eq!(balance(&env, &client, &player), PLAYER_BALANCE);
+    assert_eq!(balance(&env, &client, &committer), PLAYER_BALANCE);
+
+    client.unpause();
+    client.place_bet(&player, &round_id, &bet_amount, &seed, &None);
+
+    client.pause();
+    let result = client.try_close_betting(&round_id);
+    assert_eq!(result.err(), Some(Ok(Error::ContractPaused)));
+    let result = client.try_start_round(&round_id, &server_seed);
+    assert_eq!(result.err(), Some(Ok(Error::ContractPaused)));
+    assert_eq!(client.get_round(&round_id).status, RoundStatus::Waiting);
+
+    client.unpause();
+    client.start_round(&round_id, &server_seed);
+    assert_eq!(client.get_round(&round_id).status, RoundStatus::InProgress);
+}
+
+#[test]
+fn test_paused_players_can_exit() {
+    let env = Env::default();
+    let (admin, client) = create_test_contract(&env);
+
+    // Round 1 is running with two bets
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    client.create_round(&server_seed_hash);
+    let player = create_player(&env, &client);
+    let other = create_player(&env, &client);
+    let bet_amount = 100_000_000i128;
+    let seed = generate_seed(&env, 111);
+    let bet_id = client.place_bet(&player, &1, &bet_amount, &seed, &None);
+    let other_bet_id = client.place_bet(&other, &1, &bet_amount, &seed, &None);
+    client.start_round(&1, &server_seed);
+
+    // An LP queued part of their shares before the pause
+    let queued = client.get_lp_shares(&admin) / 2;
+    client.withdraw_liquidity(&admin, &queued);
+
+    client.pause();
+
+    // Cash outs still pay, in full or in part (1.50x, crash at 1.60x)
+    advance_time(&env, 7);
+    assert_eq!(client.get_multiplier(&1), 150);
+    assert_eq!(client.cash_out(&player, &bet_id), 145_500_000);
+    assert_eq!(client.cash_out_partial(&other, &other_bet_id, &5_000), 72_750_000);
+
+    // Running round can still be settled, and queued withdrawals claimed
+    advance_to_crash(&env, &client, 1);
+    client.finalize_round(&1);
+    assert_eq!(client.get_round(&1).status, RoundStatus::Ended);
+    assert!(client.claim_withdrawal(&admin) > 0);
+
+    // Committed seeds can still be revealed
+    client.unpause();
+    client.create_round(&server_seed_hash);
+    let client_seed = generate_seed(&env, 222);
+    let committed_bet_id =
+        client.place_committed_bet(&other, &2, &bet_amount, &hash_seed(&env, &client_seed), &None);
+    client.close_betting(&2);
+    client.pause();
+    client.reveal_client_seed(&other, &committed_bet_id, &client_seed);
+
+    // Round that never starts can be refunded
+    advance_time(&env, client.get_config().waiting_timeout);
+    assert_eq!(client.claim_refund(&committed_bet_id), bet_amount);
+
+    // Views keep working
+    assert!(client.verify_round(&1).verified);
+    assert_eq!(client.get_bet(&bet_id).status, BetStatus::CashedOut);
+    assert_eq!(client.get_pool().total_bets, 2 * bet_amount);
+    assert_eq!(client.get_config(), default_config());
+
+    // LPs can exit too
+    let shares = client.get_lp_shares(&admin);
+    assert!(client.withdraw_liquidity(&admin, &shares) > 0);
+    assert_eq!(client.get_lp_shares(&admin), 0);
+}
+
+#[test]
+fn test_migrate_v1_layout() {
+    let env = Env::default();
+    let (admin, client) = create_test_contract(&env);
+    assert_eq!(client.version(), storage::SCHEMA_VERSION);
+
+    let round_id = 7u64;
+    let bet_id = 99u64;
+    let player = Address::generate(&env);
+    let server_seed_hash = hash_seed(&env, &generate_seed(&env, SERVER_SEED));
+    let client_seeds = vec![&env, generate_seed(&env, 111)];
+    let old_round = types::RoundV1 {
+        id: round_id,
+        status: RoundStatus::Ended,
+        server_seed_hash: server_seed_hash.clone(),
+        crash_multiplier: 160,
+        created_at: 10,
+        started_at: 20,
+        ended_at: 30,
+        total_bet_amount: 300_000_000,
+        total_payout: 145_500_000,
+        bet_count: 2,
+        client_seeds: client_seeds.clone(),
+    };
+    let old_bet = types::BetV1 {
+        id: bet_id,
+        round_id,
+        player: player.clone(),
+        amount: 100_000_000,
+        cash_out_multiplier: 150,
+        payout: 145_500_000,
+        status: BetStatus::CashedOut,
+        timestamp: 10,
+    };
+    let old_pool = types::PoolV1 {
+        total_bets: 300_000_000,
+        total_payouts: 145_500_000,
+        total_house_earnings: 4_500_000,
+    };
+
+    // Storage as a version 1 deployment left it: only the admin and the
+    // pool in instance storage, rounds and bets under bare ids
+    let token = client.token();
+    env.as_contract(&client.address, || {
+        let instance = env.storage().instance();
+        instance.remove(&storage::TOKEN);
+        instance.remove(&storage::CONFIG);
+        instance.remove(&storage::VERSION);
+        for role in [Role::Operator, Role::Treasurer, Role::Pauser] {
+            instance.remove(&storage::DataKey::Role(role));
+        }
+        instance.set(&storage::POOL, &old_pool);
+        let persistent = env.storage().persistent();
+        persistent.remove(&storage::DataKey::LpShares(admin.clone()));
+        persistent.set(&round_id, &old_round);
+        persistent.set(&bet_id, &old_bet);
+    });
+    assert_eq!(client.version(), 1);
+
+    // Version 1 never stored the token, it has to be passed in
+    let ids = vec![&env, round_id, bet_id, 12345u64];
+    let result = client.try_migrate(&ids, &None);
+    assert_eq!(result.err(), Some(Ok(Error::TokenNotInitialized)));
+    assert_eq!(client.migrate(&ids, &Some(token.clone())), 2);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![
+            &env,
+            to_event(
+                &env,
+                &StorageMigrated {
+                    from_version: 1,
+                    to_version: storage::SCHEMA_VERSION,
+                }
+            ),
+        ]
+    );
+    assert_eq!(client.version(), storage::SCHEMA_VERSION);
+    assert_eq!(client.token(), token);
+    for role in [Role::Operator, Role::Treasurer, Role::Pauser] {
+        assert_eq!(client.get_role(&role), admin);
+    }
+
+    let config = client.get_config();
+    assert_eq!(config, default_config());
+    assert_eq!(
+        client.get_round(&round_id),
+        Round {
+            id: round_id,
+            status: RoundStatus::Ended,
+            server_seed_hash,
+            server_seed: None,
+            crash_multiplier: 160,
+            house_edge_bps: config.house_edge_bps,
+            max_multiplier: config.max_multiplier,
+            mix_ledger_entropy: config.mix_ledger_entropy,
+            waiting_timeout: config.waiting_timeout,
+            in_progress_timeout: config.in_progress_timeout,
+            created_at: 10,
+            started_at: 20,
+            ended_at: 30,
+            total_bet_amount: 300_000_000,
+            total_payout: 145_500_000,
+            bet_count: 2,
+            client_seeds,
+            winner_count: 0,
+            loser_count: 0,
+            house_profit: 154_500_000,
+            committed_bets: 0,
+            reveal_ends_at: 0,
+        }
+    );
+    assert_eq!(
+        client.get_bet(&bet_id),
+        Bet {
+            id: bet_id,
+            round_id,
+            player,
+            amount: 100_000_000,
+            cash_out_multiplier: 150,
+            payout: 145_500_000,
+            status: BetStatus::CashedOut,
+            timestamp: 10,
+            auto_cash_out: None,
+            partial_cash_outs: soroban_sdk::Vec::new(&env),
+        }
+    );
+    assert_eq!(
+        client.get_pool(),
+        Pool {
+            total_bets: 300_000_000,
+            total_payouts: 145_500_000,
+            total_house_earnings: 4_500_000,
+            bankroll: 0,
+            total_shares: 0,
+        }
+    );
+
+    // Running it again is a no-op
+    assert_eq!(client.migrate(&ids, &None), 0);
+    assert_eq!(contract_events(&env, &client), vec![&env]);
+
+    // The migrated deployment takes liquidity and runs rounds again
+    StellarAssetClient::new(&env, &token).mint(&admin, &HOUSE_BANKROLL);
+    client.deposit_liquidity(&admin, &HOUSE_BANKROLL);
+    let round = client.create_round(&hash_seed(&env, &generate_seed(&env, SERVER_SEED)));
+    assert_eq!(round.id, round_id + 1);
+}
+
+#[test]
+fn test_upgrade() {
+    let env = Env::default();
+    let (admin, client) = create_test_contract(&env);
+
+    // Code must have been uploaded first
+    let new_wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
+    assert!(client.try_upgrade(&new_wasm_hash).is_err());
+
+    // Only the admin can upgrade
+    let operator = Address::generate(&env);
+    client.set_role(&Role::Operator, &operator);
+    env.mock_auths(&[MockAuth {
+        address: &operator,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "upgrade",
+            args: (new_wasm_hash.clone(),).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_upgrade(&new_wasm_hash).is_err());
+    assert_eq!(client.admin(), admin);
+
+    // The admin swaps in uploaded code, which runs from the next call
+    env.mock_all_auths();
+    let new_wasm_hash = env.deployer().upload_contract_wasm(EMPTY_CONTRACT_WASM);
+    client.upgrade(&new_wasm_hash);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![
+            &env,
+            to_event(
+                &env,
+                &ContractUpgraded {
+                    new_wasm_hash: new_wasm_hash.clone(),
+                }
+            ),
+        ]
+    );
+    assert!(client.try_admin().is_err());
+}
+
+/// Smallest contract the host accepts: no functions, only the environment
+/// meta section (interface version: protocol 23, no pre-release)
+const EMPTY_CONTRACT_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // magic, version 1
+    0x00, 0x1e, 0x11, // custom section, 30 bytes, 17 byte name
+    b'c', b'o', b'n', b't', b'r', b'a', b'c', b't', b'e', b'n', b'v', b'm', b'e', b't', b'a', b'v',
+    b'0', // "contractenvmetav0"
+    0x00, 0x00, 0x00, 0x00, // ScEnvMetaKind::ScEnvMetaKindInterfaceVersion
+    0x00, 0x00, 0x00, 0x17, // protocol 23
+    0x00, 0x00, 0x00, 0x00, // pre-release 0
+];
+
+fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| li.sequence_number += ledgers);
+}
+
+fn persistent_ttl(env: &Env, client: &BalloonFlyContractClient, key: &storage::DataKey) -> u32 {
+    env.as_contract(&client.address, || env.storage().persistent().get_ttl(key))
+}
+
+#[test]
+fn test_ttl_extended_on_write() {
+    let env = Env::default();
+    let (admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    client.create_round(&hash_seed(&env, &server_seed));
+    let player = create_player(&env, &client);
+    let seed = generate_seed(&env, 111);
+    let bet_id = client.place_bet(&player, &round_id, &100_000_000, &seed, &None);
+
+    let bump = storage::PERSISTENT_BUMP_AMOUNT;
+    assert_eq!(persistent_ttl(&env, &client, &storage::DataKey::Round(round_id)), bump);
+    assert_eq!(persistent_ttl(&env, &client, &storage::DataKey::Bet(bet_id)), bump);
+    assert_eq!(persistent_ttl(&env, &client, &storage::DataKey::RoundBets(round_id)), bump);
+    assert_eq!(persistent_ttl(&env, &client, &storage::DataKey::LpShares(admin)), bump);
+
+    // Any call, even a view, keeps instance storage alive
+    advance_ledgers(&env, 2 * storage::DAY_IN_LEDGERS);
+    let instance_ttl = || env.as_contract(&client.address, || env.storage().instance().get_ttl());
+    assert_eq!(instance_ttl(), storage::INSTANCE_BUMP_AMOUNT - 2 * storage::DAY_IN_LEDGERS);
+    client.get_pool();
+    assert_eq!(instance_ttl(), storage::INSTANCE_BUMP_AMOUNT);
+}
+
+#[test]
+fn test_bump_round() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    client.create_round(&hash_seed(&env, &server_seed));
+    let player = create_player(&env, &client);
+    let seed = generate_seed(&env, 111);
+    let bet_id = client.place_bet(&player, &round_id, &100_000_000, &seed, &None);
+
+    let bump = storage::PERSISTENT_BUMP_AMOUNT;
+    advance_ledgers(&env, 3 * storage::DAY_IN_LEDGERS);
+    assert_eq!(
+        persistent_ttl(&env, &client, &storage::DataKey::Round(round_id)),
+        bump - 3 * storage::DAY_IN_LEDGERS
+    );
+
+    client.bump_round(&round_id);
+    assert_eq!(persistent_ttl(&env, &client, &storage::DataKey::Round(round_id)), bump);
+    assert_eq!(persistent_ttl(&env, &client, &storage::DataKey::Bet(bet_id)), bump);
+    assert_eq!(persistent_ttl(&env, &client, &storage::DataKey::RoundBets(round_id)), bump);
+
+    let result = client.try_bump_round(&2);
+    assert_eq!(result.err(), Some(Ok(Error::RoundNotFound)));
+}
+
+#[test]
+fn test_ended_round_expires() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    client.create_round(&hash_seed(&env, &server_seed));
+    let player = create_player(&env, &client);
+    let seed = generate_seed(&env, 111);
+    let bet_id = client.place_bet(&player, &round_id, &100_000_000, &seed, &None);
+    client.start_round(&round_id, &server_seed);
+    advance_to_crash(&env, &client, round_id);
+    client.finalize_round(&round_id);
+
+    // Ended rounds keep a fixed TTL from finalization and can't be bumped
+    let ttl = storage::ENDED_ROUND_TTL;
+    assert_eq!(persistent_ttl(&env, &client, &storage::DataKey::Round(round_id)), ttl);
+    assert_eq!(persistent_ttl(&env, &client, &storage::DataKey::Bet(bet_id)), ttl);
+    let result = client.try_bump_round(&round_id);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidRoundStatus)));
+
+    // Reads don't extend it either: it runs out `ENDED_ROUND_TTL` ledgers
+    // after finalization
+    advance_ledgers(&env, ttl);
+    assert!(client.verify_round(&round_id).verified);
+    assert_eq!(persistent_ttl(&env, &client, &storage::DataKey::Round(round_id)), 0);
+    assert_eq!(persistent_ttl(&env, &client, &storage::DataKey::Bet(bet_id)), 0);
+}
+
+#[test]
+fn test_player_bets_pagination() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let player = create_player(&env, &client);
+    let other = create_player(&env, &client);
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    let seed = generate_seed(&env, 111);
+
+    // Enough rounds to spill into a second index chunk
+    let rounds = storage::PLAYER_BETS_CHUNK_SIZE + 5;
+    let mut bet_ids = soroban_sdk::Vec::new(&env);
+    for round_id in 1..=rounds as u64 {
+        client.create_round(&server_seed_hash);
+        bet_ids.push_back(client.place_bet(&player, &round_id, &10_000_000, &seed, &None));
+        if round_id == 1 {
+            client.place_bet(&other, &1, &10_000_000, &seed, &None);
+        }
+        client.start_round(&round_id, &server_seed);
+        advance_to_crash(&env, &client, round_id);
+        client.finalize_round(&round_id);
+    }
+
+    assert_eq!(client.get_player_bet_count(&player), rounds);
+    assert_eq!(client.get_player_bet_count(&other), 1);
+
+    // First page, oldest first
+    let page = client.get_player_bets(&player, &0, &3);
+    assert_eq!(page.len(), 3);
+    for (i, bet) in page.iter().enumerate() {
+        assert_eq!(bet.id, bet_ids.get_unchecked(i as u32));
+        assert_eq!(bet.round_id, i as u64 + 1);
+        assert_eq!(bet.player, player);
+    }
+
+    // Page across the chunk boundary
+    let cursor = storage::PLAYER_BETS_CHUNK_SIZE - 2;
+    let page = client.get_player_bets(&player, &cursor, &4);
+    assert_eq!(page.len(), 4);
+    for (i, bet) in page.iter().enumerate() {
+        assert_eq!(bet.id, bet_ids.get_unchecked(cursor + i as u32));
+    }
+
+    // Last page is short, past the end is empty, limit is capped
+    assert_eq!(client.get_player_bets(&player, &(rounds - 2), &10).len(), 2);
+    assert_eq!(client.get_player_bets(&player, &rounds, &10).len(), 0);
+    assert_eq!(
+        client.get_player_bets(&player, &0, &u32::MAX).len(),
+        storage::MAX_PAGE_SIZE
+    );
+    assert_eq!(client.get_player_bets(&Address::generate(&env), &0, &10).len(), 0);
+}
+
+#[test]
+fn test_round_bets_and_cashouts() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    client.create_round(&hash_seed(&env, &server_seed));
+
+    // Seeds 111, 222, 333 crash the round at 3.49x
+    let players = [
+        create_player(&env, &client),
+        create_player(&env, &client),
+        create_player(&env, &client),
+        create_player(&env, &client),
+    ];
+    let amounts = [100_000_000i128, 200_000_000, 50_000_000, 100_000_000];
+    let seeds = [111u32, 222, 333, 444];
+    let mut bet_ids = [0u64; 4];
+    for i in 0..4 {
+        let seed = generate_seed(&env, seeds[i]);
+        bet_ids[i] = client.place_bet(&players[i], &round_id, &amounts[i], &seed, &None);
+    }
+
+    let page = client.get_round_bets(&round_id, &0, &3);
+    assert_eq!(page.len(), 3);
+    for (i, bet) in page.iter().enumerate() {
+        assert_eq!(bet.id, bet_ids[i]);
+        assert_eq!(bet.amount, amounts[i]);
+    }
+    let page = client.get_round_bets(&round_id, &3, &3);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get_unchecked(0).id, bet_ids[3]);
+
+    client.start_round(&round_id, &server_seed);
+    assert_eq!(client.get_round_cashouts(&round_id).len(), 0);
+
+    // First player out at 1.50x, then second and third at 2.01x
+    advance_time(&env, 7);
+    client.cash_out(&players[0], &bet_ids[0]);
+    advance_time(&env, 5);
+    client.cash_out(&players[1], &bet_ids[1]);
+    client.cash_out(&players[2], &bet_ids[2]);
+    advance_to_crash(&env, &client, round_id);
+    client.finalize_round(&round_id);
+
+    let cashouts = client.get_round_cashouts(&round_id);
+    assert_eq!(cashouts.len(), 3);
+    assert_eq!(cashouts.get_unchecked(0).id, bet_ids[1]);
+    assert_eq!(cashouts.get_unchecked(0).payout, 389_940_000); // 20 XLM * 2.01 * 0.97
+    assert_eq!(cashouts.get_unchecked(1).id, bet_ids[0]);
+    assert_eq!(cashouts.get_unchecked(1).payout, 145_500_000); // 10 XLM * 1.50 * 0.97
+    assert_eq!(cashouts.get_unchecked(2).id, bet_ids[2]);
+    assert_eq!(cashouts.get_unchecked(2).payout, 97_485_000); // 5 XLM * 2.01 * 0.97
+
+    let result = client.try_get_round_bets(&2, &0, &10);
+    assert_eq!(result.err(), Some(Ok(Error::RoundNotFound)));
+    let result = client.try_get_round_cashouts(&2);
+    assert_eq!(result.err(), Some(Ok(Error::RoundNotFound)));
+}
+
+#[test]
+fn test_player_stats() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let player = create_player(&env, &client);
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    let seed = generate_seed(&env, 111);
+    let bet_amount = 100_000_000i128;
+
+    let stats = client.get_player_stats(&player);
+    assert_eq!(stats.rounds_played, 0);
+    assert_eq!(stats.net_pnl, 0);
+
+    // Round 1: manual cash out at 1.50x
+    client.create_round(&server_seed_hash);
+    let bet_id = client.place_bet(&player, &1, &bet_amount, &seed, &None);
+    let placed_at = env.ledger().timestamp();
+    client.start_round(&1, &server_seed);
+    advance_time(&env, 7);
+    client.cash_out(&player, &bet_id);
+    advance_to_crash(&env, &client, 1);
+    client.finalize_round(&1);
+
+    // Round 2: lost, 2.00x auto target never reached before the 1.60x crash
+    client.create_round(&server_seed_hash);
+    client.place_bet(&player, &2, &(2 * bet_amount), &seed, &Some(200));
+    client.start_round(&2, &server_seed);
+    advance_to_crash(&env, &client, 2);
+    client.finalize_round(&2);
+
+    // Round 3: auto cash out at 1.10x settled on finalization
+    client.create_round(&server_seed_hash);
+    client.place_bet(&player, &3, &bet_amount, &seed, &Some(110));
+    client.start_round(&3, &server_seed);
+    advance_time(&env, 10);
+    advance_to_crash(&env, &client, 3);
+    client.finalize_round(&3);
+
+    // Round 4: refunded, doesn't count
+    client.create_round(&server_seed_hash);
+    let refunded_id =
+        client.place_committed_bet(&player, &4, &bet_amount, &hash_seed(&env, &seed), &None);
+    let last_played = env.ledger().timestamp();
+    client.cancel_round(&4);
+    client.claim_refund(&refunded_id);
+
+    let won = 145_500_000 + 106_700_000; // 10 XLM * 1.50x and 1.10x, net of 3%
+    let wagered = 4 * bet_amount; // 10 + 20 + 10 XLM
+    assert_ne!(placed_at, last_played);
+    assert_eq!(
+        client.get_player_stats(&player),
+        PlayerStats {
+            total_wagered: wagered,
+            total_won: won,
+            rounds_played: 3,
+            biggest_multiplier: 150,
+            biggest_payout: 145_500_000,
+            net_pnl: won - wagered,
+            last_played,
+        }
+    );
+    assert_eq!(
+        balance(&env, &client, &player),
+        PLAYER_BALANCE + won - wagered
+    );
+}
+
+#[test]
+fn test_seed_chain() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    // seed_3 is random, seed_2 = sha256(seed_3), seed_1 = sha256(seed_2)
+    let seed_3 = generate_seed(&env, SERVER_SEED);
+    let seed_2 = hash_seed(&env, &seed_3);
+    let seed_1 = hash_seed(&env, &seed_2);
+    let terminal_hash = hash_seed(&env, &seed_1);
+
+    let result = client.try_create_chained_round();
+    assert_eq!(result.err(), Some(Ok(Error::SeedChainNotFound)));
+
+    client.commit_seed_chain(&terminal_hash, &3);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![
+            &env,
+            to_event(
+                &env,
+                &SeedChainCommitted {
+                    terminal_hash: terminal_hash.clone(),
+                    length: 3,
+                }
+            ),
+        ]
+    );
+
+    // Fresh commitments would leave a gap in the chain's history, and the
+    // chain can't be swapped for one the operator likes better
+    let result = client.try_create_round(&hash_seed(&env, &generate_seed(&env, 7)));
+    assert_eq!(result.err(), Some(Ok(Error::SeedChainActive)));
+    let result = client.try_commit_seed_chain(&hash_seed(&env, &seed_3), &3);
+    assert_eq!(result.err(), Some(Ok(Error::SeedChainActive)));
+
+    // Round 1 reveals seed_1
+    let round = client.create_chained_round();
+    assert_eq!(round.server_seed_hash, terminal_hash);
+    client.start_round(&round.id, &seed_1);
+    advance_to_crash(&env, &client, round.id);
+    client.finalize_round(&round.id);
+    let chain = client.get_seed_chain().unwrap();
+    assert_eq!(chain.head, seed_1);
+    assert_eq!(chain.revealed, 1);
+
+    // A cancelled round reveals nothing, the next one reuses the link
+    let round = client.create_chained_round();
+    assert_eq!(round.server_seed_hash, seed_1);
+    client.cancel_round(&round.id);
+    let round = client.create_chained_round();
+    assert_eq!(round.server_seed_hash, seed_1);
+
+    // Only the next link is accepted
+    let result = client.try_start_round(&round.id, &seed_3);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidServerSeedHash)));
+    client.start_round(&round.id, &seed_2);
+    advance_to_crash(&env, &client, round.id);
+    client.finalize_round(&round.id);
+
+    let round = client.create_chained_round();
+    assert_eq!(round.server_seed_hash, seed_2);
+    client.start_round(&round.id, &seed_3);
+    advance_to_crash(&env, &client, round.id);
+    client.finalize_round(&round.id);
+
+    // Every round links to the one before it
+    assert_eq!(client.get_round(&4).server_seed_hash, client.get_round(&3).server_seed.unwrap());
+    let verification = client.verify_round(&4);
+    assert!(verification.verified);
+    assert_eq!(
+        verification.seed_chain,
+        ChainSource::Link(SeedChainLink {
+            terminal_hash: terminal_hash.clone(),
+            position: 3,
+            length: 3,
+        })
+    );
+    let verification = client.verify_round(&3);
+    assert_eq!(
+        verification.seed_chain,
+        ChainSource::Link(SeedChainLink {
+            terminal_hash: terminal_hash.clone(),
+            position: 2,
+            length: 3,
+        })
+    );
+
+    let chain = client.get_seed_chain().unwrap();
+    assert_eq!(chain.head, seed_3);
+    assert_eq!(chain.revealed, 3);
+    let result = client.try_create_chained_round();
+    assert_eq!(result.err(), Some(Ok(Error::SeedChainExhausted)));
+
+    let result = client.try_commit_seed_chain(&terminal_hash, &0);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidAmount)));
+
+    // Once used up the chain can be replaced, or rounds committed one by one
+    let round = client.create_round(&hash_seed(&env, &generate_seed(&env, 7)));
+    client.cancel_round(&round.id);
+    client.commit_seed_chain(&hash_seed(&env, &seed_3), &1);
+    assert_eq!(client.get_seed_chain().unwrap().revealed, 0);
+}
+
+#[test]
+fn test_committed_client_seeds() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    let round = client.create_round(&hash_seed(&env, &server_seed));
+    let bet_amount = 100_000_000i128;
+
+    // One plain seed, two committed ones
+    let plain = create_player(&env, &client);
+    let revealer = create_player(&env, &client);
+    let withholder = create_player(&env, &client);
+    let plain_bet = client.place_bet(&plain, &round.id, &bet_amount, &generate_seed(&env, 111), &None);
+    let revealed_seed = generate_seed(&env, 222);
+    let revealed_bet = client.place_committed_bet(
+        &revealer,
+        &round.id,
+        &bet_amount,
+        &hash_seed(&env, &revealed_seed),
+        &None,
+    );
+    let withheld_seed = generate_seed(&env, 333);
+    let withheld_bet = client.place_committed_bet(
+        &withholder,
+        &round.id,
+        &bet_amount,
+        &hash_seed(&env, &withheld_seed),
+        &None,
+    );
+
+    // Reveals wait for the operator to close betting, and so does the start
+    let result = client.try_reveal_client_seed(&revealer, &revealed_bet, &revealed_seed);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidRoundStatus)));
+    assert_eq!(client.get_round(&round.id).committed_bets, 2);
+    let result = client.try_start_round(&round.id, &server_seed);
+    assert_eq!(result.err(), Some(Ok(Error::RevealPhaseRequired)));
+
+    client.close_betting(&round.id);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![&env, to_event(&env, &BettingClosed { round_id: round.id })]
+    );
+    let revealing = client.get_round(&round.id);
+    assert_eq!(revealing.status, RoundStatus::Revealing);
+    assert_eq!(
+        revealing.reveal_ends_at,
+        env.ledger().timestamp() + client.get_config().min_reveal_window
+    );
+    assert_eq!(client.current_round().unwrap().id, round.id);
+
+    // No more bets during the reveal phase
+    let late = create_player(&env, &client);
+    let result = client.try_place_bet(&late, &round.id, &bet_amount, &generate_seed(&env, 444), &None);
+    assert_eq!(result.err(), Some(Ok(Error::BettingClosed)));
+    let result = client.try_close_betting(&round.id);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidRoundStatus)));
+
+    // Seed must match the commitment, and only the bettor can reveal it
+    let result = client.try_reveal_client_seed(&revealer, &revealed_bet, &withheld_seed);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidClientSeed)));
+    let result = client.try_reveal_client_seed(&plain, &revealed_bet, &revealed_seed);
+    assert_eq!(result.err(), Some(Ok(Error::Unauthorized)));
+    let result = client.try_reveal_client_seed(&plain, &plain_bet, &revealed_seed);
+    assert_eq!(result.err(), Some(Ok(Error::SeedNotCommitted)));
+
+    client.reveal_client_seed(&revealer, &revealed_bet, &revealed_seed);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![
+            &env,
+            to_event(
+                &env,
+                &SeedRevealed {
+                    round_id: round.id,
+                    player: revealer.clone(),
+                    bet_id: revealed_bet,
+                    client_seed: revealed_seed.clone(),
+                }
+            ),
+        ]
+    );
+    let result = client.try_reveal_client_seed(&revealer, &revealed_bet, &revealed_seed);
+    assert_eq!(result.err(), Some(Ok(Error::SeedNotCommitted)));
+
+    // Players get the whole reveal window
+    let result = client.try_start_round(&round.id, &server_seed);
+    assert_eq!(result.err(), Some(Ok(Error::RevealWindowOpen)));
+    advance_time(&env, client.get_config().min_reveal_window);
+
+    // Starting voids the unrevealed bet, keeping 10% of its stake
+    let bankroll = client.get_pool().bankroll;
+    client.start_round(&round.id, &server_seed);
+    let penalty = 10_000_000i128;
+    let events = contract_events(&env, &client);
+    assert_eq!(
+        events.slice(0..1),
+        vec![
+            &env,
+            to_event(
+                &env,
+                &BetForfeited {
+                    round_id: round.id,
+                    player: withholder.clone(),
+                    bet_id: withheld_bet,
+                    refund: bet_amount - penalty,
+                    penalty,
+                }
+            ),
+        ]
+    );
+    let bet = client.get_bet(&withheld_bet);
+    assert_eq!(bet.status, BetStatus::Forfeited);
+    assert_eq!(bet.payout, bet_amount - penalty);
+    assert_eq!(client.get_pool().bankroll, bankroll + penalty);
+    assert_eq!(client.get_player_stats(&withholder).net_pnl, -penalty);
+
+    // The refund is credited, the player collects it
+    assert_eq!(balance(&env, &client, &withholder), PLAYER_BALANCE - bet_amount);
+    assert_eq!(client.get_unpaid_payout(&withholder), bet_amount - penalty);
+    assert_eq!(client.claim_payout(&withholder), bet_amount - penalty);
+    assert_eq!(
+        contract_events(&env, &client),
+        vec![
+            &env,
+            to_event(
+                &env,
+                &PayoutClaimed {
+                    player: withholder.clone(),
+                    amount: bet_amount - penalty,
+                }
+            ),
+        ]
+    );
+    assert_eq!(balance(&env, &client, &withholder), PLAYER_BALANCE - penalty);
+    let result = client.try_claim_payout(&withholder);
+    assert_eq!(result.err(), Some(Ok(Error::NothingToClaim)));
+
+    // Crash point mixes the plain seed and the revealed one
+    let round = client.get_round(&round.id);
+    assert_eq!(round.total_bet_amount, 2 * bet_amount);
+    assert_eq!(
+        round.client_seeds,
+        vec![&env, generate_seed(&env, 111), revealed_seed.clone()]
+    );
+    assert!(client.verify_round(&round.id).verified);
+
+    let result = client.try_reveal_client_seed(&withholder, &withheld_bet, &withheld_seed);
+    assert_eq!(result.err(), Some(Ok(Error::InvalidRoundStatus)));
+
+    // Forfeited bets are left alone at settlement
+    advance_to_crash(&env, &client, round.id);
+    client.finalize_round(&round.id);
+    assert_eq!(client.get_round(&round.id).loser_count, 2);
+    assert_eq!(client.get_bet(&withheld_bet).status, BetStatus::Forfeited);
+}
+
+#[test]
+fn test_migrate_bet_layout() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round_id = 1u64;
+    client.create_round(&hash_seed(&env, &generate_seed(&env, SERVER_SEED)));
+    let player = create_player(&env, &client);
+    let bet_id = client.place_bet(&player, &round_id, &100_000_000, &generate_seed(&env, 111), &Some(150));
+    let bet = client.get_bet(&bet_id);
+
+    // Bet as stored before partial cash-outs
+    env.as_contract(&client.address, || {
+        let old_bet = types::BetV2 {
+            id: bet.id,
+            round_id: bet.round_id,
+            player: bet.player.clone(),
+            amount: bet.amount,
+            cash_out_multiplier: bet.cash_out_multiplier,
+            payout: bet.payout,
+            status: bet.status.clone(),
+            timestamp: bet.timestamp,
+            auto_cash_out: bet.auto_cash_out,
+        };
+        env.storage().persistent().set(&storage::DataKey::Bet(bet_id), &old_bet);
+        env.storage().instance().set(&storage::VERSION, &5u32);
+    });
+
+    assert_eq!(client.migrate(&vec![&env, bet_id], &None), 1);
+    assert_eq!(client.version(), storage::SCHEMA_VERSION);
+    assert_eq!(client.get_bet(&bet_id), bet);
+}
+
+#[test]
+fn test_migrate_round_layout() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round = client.create_round(&hash_seed(&env, &generate_seed(&env, SERVER_SEED)));
+    client.set_config(&Config {
+        mix_ledger_entropy: true,
+        ..client.get_config()
+    });
+
+    // Round as stored before the ledger entropy switch was snapshotted
+    env.as_contract(&client.address, || {
+        let old_round = types::RoundV2 {
+            id: round.id,
+            status: round.status.clone(),
+            server_seed_hash: round.server_seed_hash.clone(),
+            server_seed: round.server_seed.clone(),
+            crash_multiplier: round.crash_multiplier,
+            house_edge_bps: round.house_edge_bps,
+            max_multiplier: round.max_multiplier,
+            created_at: round.created_at,
+            started_at: round.started_at,
+            ended_at: round.ended_at,
+            total_bet_amount: round.total_bet_amount,
+            total_payout: round.total_payout,
+            bet_count: round.bet_count,
+            client_seeds: round.client_seeds.clone(),
+            winner_count: round.winner_count,
+            loser_count: round.loser_count,
+            house_profit: round.house_profit,
+        };
+        env.storage().persistent().set(&storage::DataKey::Round(round.id), &old_round);
+        env.storage().instance().set(&storage::VERSION, &7u32);
+    });
+
+    // Open rounds keep the behavior they had: the config at start time
+    assert_eq!(client.migrate(&vec![&env, round.id], &None), 1);
+    assert_eq!(client.version(), storage::SCHEMA_VERSION);
+    assert_eq!(
+        client.get_round(&round.id),
+        Round {
+            mix_ledger_entropy: true,
+            ..round
+        }
+    );
+}
+
+#[test]
+fn test_migrate_round_timeouts() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let round = client.create_round(&hash_seed(&env, &generate_seed(&env, SERVER_SEED)));
+    let config = Config {
+        waiting_timeout: 600,
+        in_progress_timeout: 1_200,
+        ..client.get_config()
+    };
+    client.set_config(&config);
+
+    // Round as stored before the refund timeouts were snapshotted
+    env.as_contract(&client.address, || {
+        let old_round = types::RoundV4 {
+            id: round.id,
+            status: round.status.clone(),
+            server_seed_hash: round.server_seed_hash.clone(),
+            server_seed: round.server_seed.clone(),
+            crash_multiplier: round.crash_multiplier,
+            house_edge_bps: round.house_edge_bps,
+            max_multiplier: round.max_multiplier,
+            mix_ledger_entropy: round.mix_ledger_entropy,
+            created_at: round.created_at,
+            started_at: round.started_at,
+            ended_at: round.ended_at,
+            total_bet_amount: round.total_bet_amount,
+            total_payout: round.total_payout,
+            bet_count: round.bet_count,
+            client_seeds: round.client_seeds.clone(),
+            winner_count: round.winner_count,
+            loser_count: round.loser_count,
+            house_profit: round.house_profit,
+            committed_bets: round.committed_bets,
+            reveal_ends_at: round.reveal_ends_at,
+        };
+        env.storage().persistent().set(&storage::DataKey::Round(round.id), &old_round);
+        env.storage().instance().set(&storage::VERSION, &10u32);
+    });
+
+    // Those rounds read the timeouts from the config, so they keep its values
+    assert_eq!(client.migrate(&vec![&env, round.id], &None), 1);
+    assert_eq!(client.version(), storage::SCHEMA_VERSION);
+    assert_eq!(
+        client.get_round(&round.id),
+        Round {
+            waiting_timeout: 600,
+            in_progress_timeout: 1_200,
+            ..round.clone()
+        }
+    );
+    assert_eq!(client.migrate(&vec![&env, round.id], &None), 0);
+}
+
+#[test]
+fn test_migrate_config_layout() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    // Config as stored before the unrevealed seed penalty, ledger entropy, bet caps
+    // and reveal window
+    env.as_contract(&client.address, || {
+        let mut fields: soroban_sdk::Map<soroban_sdk::Symbol, Val> =
+            env.storage().instance().get(&storage::CONFIG).unwrap();
+        fields.remove(soroban_sdk::Symbol::new(&env, "unrevealed_penalty_bps"));
+        fields.remove(soroban_sdk::Symbol::new(&env, "mix_ledger_entropy"));
+        fields.remove(soroban_sdk::Symbol::new(&env, "max_bets_per_player"));
+        fields.remove(soroban_sdk::Symbol::new(&env, "max_bets_per_round"));
+        fields.remove(soroban_sdk::Symbol::new(&env, "min_reveal_window"));
+        env.storage().instance().set(&storage::CONFIG, &fields);
+        env.storage().instance().set(&storage::VERSION, &2u32);
+    });
+
+    client.migrate(&soroban_sdk::Vec::new(&env), &None);
+    assert_eq!(client.version(), storage::SCHEMA_VERSION);
+    assert_eq!(client.get_config(), default_config());
+}
+
+#[test]
+fn test_ledger_entropy() {
+    let env = Env::default();
+    let (_admin, client) = create_test_contract(&env);
+
+    let server_seed = generate_seed(&env, SERVER_SEED);
+    let server_seed_hash = hash_seed(&env, &server_seed);
+    let client_seed = generate_seed(&env, 111);
+    let player = create_player(&env, &client);
+
+    // Off by default: the crash point only depends on the seeds
+    let round = client.create_round(&server_seed_hash);
+    assert!(!round.mix_ledger_entropy);
+    client.place_bet(&player, &round.id, &100_000_000, &client_seed, &None);
+
+    // The switch is fixed when the round is created
+    client.set_config(&Config {
+        mix_ledger_entropy: true,
+        ..client.get_config()
+    });
+    client.start_round(&round.id, &server_seed);
+    advance_to_crash(&env, &client, round.id);
+    client.finalize_round(&round.id);
+    let verification = client.verify_round(&round.id);
+    assert_eq!(verification.entropy, EntropySource::None);
+    assert_eq!(
+        verification.formula,
+        String::from_str(&env, fairness::ROUND_HASH_FORMULA)
+    );
+    assert_eq!(verification.crash_multiplier, 160);
+
+    let round = client.create_round(&server_seed_hash);
+    assert!(round.mix_ledger_entropy);
+    client.place_bet(&player, &round.id, &100_000_000, &client_seed, &None);
+    client.set_config(&Config {
+        mix_ledger_entropy: false,
+        ..client.get_config()
+    });
+    env.ledger().with_mut(|li| li.sequence_number = 4_242);
+    client.start_round(&round.id, &server_seed);
+
+    let verification = client.verify_round(&round.id);
+    assert!(verification.verified);
+    assert_eq!(
+        verification.formula,
+        String::from_str(&env, fairness::ROUND_HASH_WITH_ENTROPY_FORMULA)
+    );
+
+    // Reproduce the published formula from the published inputs
+    let EntropySource::Ledger(entropy) = verification.entropy else {
+        panic!("ledger entropy not mixed in");
+    };
+    assert_eq!(entropy.ledger_sequence, 4_242);
+    assert_eq!(entropy.ledger_timestamp, env.ledger().timestamp());
+    let mut preimage = soroban_sdk::Bytes::from_array(&env, &4_242u32.to_be_bytes());
+    preimage.extend_from_array(&entropy.ledger_timestamp.to_be_bytes());
+    preimage.append(&entropy.prng_seed.clone().into());
+    let expected_entropy: BytesN<32> = env.crypto().sha256(&preimage).into();
+    assert_eq!(entropy.entropy, expected_entropy);
+
+    let mut message = soroban_sdk::Bytes::from_array(&env, &client_seed.to_array());
+    message.append(&expected_entropy.into());
+    let round_hash = fairness::hmac_sha256(&env, &server_seed, &message);
+    assert_eq!(verification.round_hash, round_hash);
+    assert_eq!(
+        client.get_round(&round.id).crash_multiplier,
+        fairness::crash_multiplier(&round_hash, round.house_edge_bps, round.max_multiplier)
+    );
+}
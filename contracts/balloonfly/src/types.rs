@@ -1,11 +1,47 @@
-use soroban_sdk::{contracttype, Address, BytesN, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum RoundStatus {
     Waiting,
+    Revealing, // Betting closed, committed client seeds are being revealed
     InProgress,
     Ended,
+    Cancelled,
+}
+
+/// Roles the admin can assign, each held by a single address
+///
+/// Kept in-house rather than on the workspace's `stellar-access`: its
+/// `AccessControl` grants `Symbol` roles to any number of accounts, managed
+/// by per-role admins, while each role here is one address that `get_role`
+/// returns and `set_role` replaces. Moving over would change both entry
+/// points and need a migration of the stored `DataKey::Role` entries.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    Operator, // Creates, starts, finalizes and cancels rounds
+    Treasurer, // Updates game and bankroll parameters
+    Pauser, // Pauses and unpauses the game
+}
+
+/// Game parameters, updatable by the treasurer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Config {
+    pub house_edge_bps: u32, // 300 = 3%
+    pub min_bet: i128, // In stroops
+    pub max_bet: i128, // In stroops
+    pub max_multiplier: u64, // Crash point cap, 100 = 1.00x
+    pub max_client_seeds: u32, // Client seeds collected per round
+    pub waiting_timeout: u64, // Seconds after creation before a round can be refunded
+    pub in_progress_timeout: u64, // Seconds after start before a round can be refunded
+    pub max_exposure_bps: u32, // Max worst-case round payout as a share of the bankroll
+    pub unrevealed_penalty_bps: u32, // Share of the stake kept when a committed seed isn't revealed
+    pub mix_ledger_entropy: bool, // Mix ledger data drawn at round start into the crash point
+    pub max_bets_per_player: u32, // Bets a player can place in one round
+    pub max_bets_per_round: u32, // Bets a round takes, bounds the work done to settle it
+    pub min_reveal_window: u64, // Seconds after `close_betting` before the round can start
 }
 
 #[contracttype]
@@ -14,14 +50,95 @@ pub struct Round {
     pub id: u64,
     pub status: RoundStatus,
     pub server_seed_hash: BytesN<32>,
+    pub server_seed: Option<BytesN<32>>, // Revealed when the round starts
     pub crash_multiplier: u64, // 100 = 1.00x, 250 = 2.50x
+    pub house_edge_bps: u32, // Config at creation, used for payouts and the crash point
+    pub max_multiplier: u64, // Config at creation, caps the crash point
+    pub mix_ledger_entropy: bool, // Config at creation, mixes ledger entropy into the crash point
+    pub waiting_timeout: u64, // Config at creation, refund deadline before the start
+    pub in_progress_timeout: u64, // Config at creation, refund deadline after the start
     pub created_at: u64,
     pub started_at: u64,
     pub ended_at: u64,
     pub total_bet_amount: i128,
     pub total_payout: i128,
     pub bet_count: u32,
-    pub client_seeds: Vec<BytesN<32>>, // First 3 client seeds for provably fair
+    pub client_seeds: Vec<BytesN<32>>, // `max_client_seeds` plain seeds + revealed committed seeds
+    pub winner_count: u32, // Set on finalization
+    pub loser_count: u32, // Set on finalization
+    pub house_profit: i128, // total_bet_amount - total_payout, set on finalization
+    pub committed_bets: u32, // Bets placed with a seed commitment
+    pub reveal_ends_at: u64, // Set by `close_betting`, the round can't start before it
+}
+
+/// Commitment to a SHA-256 chain of server seeds
+///
+/// The operator draws a random `seed_n`, computes `seed_{i-1} = sha256(seed_i)`
+/// down to `seed_1` and commits `terminal_hash = sha256(seed_1)`. Rounds then
+/// reveal `seed_1`, `seed_2`, ... in order, each hashing to the link revealed
+/// before it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeedChain {
+    pub terminal_hash: BytesN<32>,
+    pub length: u32, // Number of seeds in the chain
+    pub head: BytesN<32>, // Last revealed link, the next seed must hash to it
+    pub revealed: u32, // Seeds revealed so far
+}
+
+/// Data drawn when a round starts, which the operator can't know when
+/// committing to the server seed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundEntropy {
+    pub ledger_sequence: u32,
+    pub ledger_timestamp: u64,
+    pub prng_seed: BytesN<32>, // env.prng() output in the start_round call
+    pub entropy: BytesN<32>, // SHA256(ledger_sequence || ledger_timestamp || prng_seed), big-endian
+}
+
+/// Position of a round's server seed in the seed chain it was revealed from
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeedChainLink {
+    pub terminal_hash: BytesN<32>, // Chain the seed belongs to
+    pub position: u32, // 1 for the first seed revealed
+    pub length: u32, // Number of seeds in the chain
+}
+
+/// Seed chain a round's server seed was revealed from, if any
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ChainSource {
+    None,
+    Link(SeedChainLink),
+}
+
+/// Extra data mixed into a round's hash besides the seeds
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EntropySource {
+    None,
+    Ledger(RoundEntropy),
+}
+
+/// Result of recomputing a round's provably fair outcome from stored seeds
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundVerification {
+    pub round_id: u64,
+    pub server_seed_hash: BytesN<32>, // Committed before bets
+    pub server_seed: BytesN<32>, // Revealed at start
+    pub seed_chain: ChainSource,
+    pub client_seeds: Vec<BytesN<32>>,
+    pub entropy: EntropySource,
+    pub formula: String, // How round_hash is computed
+    pub round_hash: BytesN<32>, // HMAC_SHA256(server_seed, client_seeds [|| entropy])
+    pub crash_multiplier: u64, // Recomputed from round_hash
+    pub recorded_crash_multiplier: u64, // Stored on the round
+    pub seed_hash_valid: bool, // sha256(server_seed) == server_seed_hash
+    pub crash_multiplier_valid: bool, // crash_multiplier == recorded_crash_multiplier
+    pub verified: bool, // Both checks passed
 }
 
 #[contracttype]
@@ -30,6 +147,8 @@ pub enum BetStatus {
     Active,
     CashedOut,
     Lost,
+    Refunded,
+    Forfeited, // Committed seed not revealed, stake returned minus the penalty
 }
 
 #[contracttype]
@@ -40,9 +159,32 @@ pub struct Bet {
     pub player: Address,
     pub amount: i128,
     pub cash_out_multiplier: u64, // 0 if not cashed out
-    pub payout: i128, // 0 if not cashed out
+    pub payout: i128, // Paid out so far, partial cash-outs included
     pub status: BetStatus,
     pub timestamp: u64,
+    pub auto_cash_out: Option<u64>, // Target multiplier, None for manual cash out
+    pub partial_cash_outs: Vec<PartialCashOut>, // Stake secured before the final settlement
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PartialCashOut {
+    pub amount: i128, // Part of the stake cashed out
+    pub multiplier: u64,
+    pub payout: i128, // Net of the house edge
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerStats {
+    pub total_wagered: i128, // Stakes placed, refunds excluded
+    pub total_won: i128, // Payouts received, net of the house edge
+    pub rounds_played: u32,
+    pub biggest_multiplier: u64, // Best cash-out, 100 = 1.00x
+    pub biggest_payout: i128,
+    pub net_pnl: i128, // total_won - total_wagered
+    pub last_played: u64, // Timestamp of the last bet
 }
 
 #[contracttype]
@@ -51,5 +193,158 @@ pub struct Pool {
     pub total_bets: i128,
     pub total_payouts: i128,
     pub total_house_earnings: i128,
+    pub bankroll: i128, // House funds available to pay winners (excludes escrowed stakes)
+    pub total_shares: i128, // LP shares outstanding, each a claim on bankroll / total_shares
+}
+
+/// LP shares a provider queued for withdrawal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedWithdrawal {
+    pub shares: i128,
+    pub epoch: u32, // Withdrawal epoch the shares are burned in
+}
+
+/// Settled share price of a withdrawal epoch
+///
+/// All shares queued in the epoch are burned together when the last open
+/// round closes. Each provider then gets `shares * amount / total shares`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettledEpoch {
+    pub shares: i128, // Shares burned when the epoch closed
+    pub amount: i128, // Bankroll they were worth
+}
+
+
+/// Round layout of schema version 1, before seeds, config snapshots and
+/// settlement stats were stored on the round
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundV1 {
+    pub id: u64,
+    pub status: RoundStatus,
+    pub server_seed_hash: BytesN<32>,
+    pub crash_multiplier: u64,
+    pub created_at: u64,
+    pub started_at: u64,
+    pub ended_at: u64,
+    pub total_bet_amount: i128,
+    pub total_payout: i128,
+    pub bet_count: u32,
+    pub client_seeds: Vec<BytesN<32>>,
+}
+
+/// Round layout of schema versions 2 to 7, before the ledger entropy switch
+/// was stored on the round
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundV2 {
+    pub id: u64,
+    pub status: RoundStatus,
+    pub server_seed_hash: BytesN<32>,
+    pub server_seed: Option<BytesN<32>>,
+    pub crash_multiplier: u64,
+    pub house_edge_bps: u32,
+    pub max_multiplier: u64,
+    pub created_at: u64,
+    pub started_at: u64,
+    pub ended_at: u64,
+    pub total_bet_amount: i128,
+    pub total_payout: i128,
+    pub bet_count: u32,
+    pub client_seeds: Vec<BytesN<32>>,
+    pub winner_count: u32,
+    pub loser_count: u32,
+    pub house_profit: i128,
+}
+
+/// Round layout of schema versions 8 and 9, before the reveal phase was
+/// tracked on the round
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundV3 {
+    pub id: u64,
+    pub status: RoundStatus,
+    pub server_seed_hash: BytesN<32>,
+    pub server_seed: Option<BytesN<32>>,
+    pub crash_multiplier: u64,
+    pub house_edge_bps: u32,
+    pub max_multiplier: u64,
+    pub mix_ledger_entropy: bool,
+    pub created_at: u64,
+    pub started_at: u64,
+    pub ended_at: u64,
+    pub total_bet_amount: i128,
+    pub total_payout: i128,
+    pub bet_count: u32,
+    pub client_seeds: Vec<BytesN<32>>,
+    pub winner_count: u32,
+    pub loser_count: u32,
+    pub house_profit: i128,
+}
+
+/// Round layout of schema version 10, before the refund timeouts were
+/// stored on the round
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundV4 {
+    pub id: u64,
+    pub status: RoundStatus,
+    pub server_seed_hash: BytesN<32>,
+    pub server_seed: Option<BytesN<32>>,
+    pub crash_multiplier: u64,
+    pub house_edge_bps: u32,
+    pub max_multiplier: u64,
+    pub mix_ledger_entropy: bool,
+    pub created_at: u64,
+    pub started_at: u64,
+    pub ended_at: u64,
+    pub total_bet_amount: i128,
+    pub total_payout: i128,
+    pub bet_count: u32,
+    pub client_seeds: Vec<BytesN<32>>,
+    pub winner_count: u32,
+    pub loser_count: u32,
+    pub house_profit: i128,
+    pub committed_bets: u32,
+    pub reveal_ends_at: u64,
+}
+
+/// Bet layout of schema version 1, before auto cash-out
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BetV1 {
+    pub id: u64,
+    pub round_id: u64,
+    pub player: Address,
+    pub amount: i128,
+    pub cash_out_multiplier: u64,
+    pub payout: i128,
+    pub status: BetStatus,
+    pub timestamp: u64,
 }
 
+/// Bet layout of schema versions 2 to 5, before partial cash-outs
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BetV2 {
+    pub id: u64,
+    pub round_id: u64,
+    pub player: Address,
+    pub amount: i128,
+    pub cash_out_multiplier: u64,
+    pub payout: i128,
+    pub status: BetStatus,
+    pub timestamp: u64,
+    pub auto_cash_out: Option<u64>,
+}
+
+/// Pool layout of schema version 1, before the bankroll and LP shares
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolV1 {
+    pub total_bets: i128,
+    pub total_payouts: i128,
+    pub total_house_earnings: i128,
+}
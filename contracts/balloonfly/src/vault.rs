@@ -0,0 +1,172 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    error::Error,
+    events::{LiquidityWithdrawn, WithdrawalClaimed},
+    storage::{
+        get_claimable_withdrawal, get_lp_shares, get_open_rounds, get_pool, get_queued_shares,
+        get_queued_withdrawal, get_settled_epoch, get_withdrawal_epoch, remove_queued_withdrawal,
+        set_claimable_withdrawal, set_lp_shares, set_open_rounds, set_pool, set_queued_shares,
+        set_queued_withdrawal, set_settled_epoch, set_withdrawal_epoch,
+    },
+    transfer,
+    types::{QueuedWithdrawal, SettledEpoch},
+};
+
+/// Share price scale (1 share = 1.0000000 token units at launch)
+pub const SHARE_PRICE_SCALE: i128 = 10_000_000;
+
+/// Price of one LP share in token units, scaled by `SHARE_PRICE_SCALE`
+pub fn share_price(env: &Env) -> i128 {
+    let pool = get_pool(env);
+    if pool.total_shares == 0 {
+        return SHARE_PRICE_SCALE;
+    }
+    pool.bankroll * SHARE_PRICE_SCALE / pool.total_shares
+}
+
+/// Shares minted for a deposit, priced at the bankroll's net asset value
+pub fn shares_for_deposit(env: &Env, amount: i128) -> Result<i128, Error> {
+    let pool = get_pool(env);
+    if pool.total_shares == 0 {
+        return Ok(amount);
+    }
+    // A wiped out bankroll with shares outstanding can't be priced
+    if pool.bankroll <= 0 {
+        return Err(Error::InsufficientBankroll);
+    }
+    Ok(amount * pool.total_shares / pool.bankroll)
+}
+
+/// Burn a provider's shares and pay out their part of the bankroll
+pub fn withdraw(env: &Env, provider: &Address, shares: i128) -> Result<i128, Error> {
+    let mut pool = get_pool(env);
+    let amount = shares * pool.bankroll / pool.total_shares;
+    pool.total_shares -= shares;
+    pool.bankroll -= amount;
+    set_pool(env, &pool);
+
+    LiquidityWithdrawn {
+        provider: provider.clone(),
+        shares,
+        amount,
+    }
+    .publish(env);
+
+    transfer(env, &env.current_contract_address(), provider, amount)?;
+    Ok(amount)
+}
+
+/// Move a provider's shares to the current withdrawal epoch
+pub fn queue_withdrawal(env: &Env, provider: &Address, shares: i128) {
+    set_lp_shares(env, provider, get_lp_shares(env, provider) - shares);
+    settle_withdrawal(env, provider);
+
+    // Anything still queued after settling is in the current epoch
+    let queued = get_queued_withdrawal(env, provider).map_or(0, |queued| queued.shares);
+    let epoch = get_withdrawal_epoch(env);
+    set_queued_withdrawal(
+        env,
+        provider,
+        &QueuedWithdrawal {
+            shares: queued + shares,
+            epoch,
+        },
+    );
+    set_queued_shares(env, get_queued_shares(env) + shares);
+}
+
+/// LP shares a provider queued that aren't burned yet
+pub fn pending_shares(env: &Env, provider: &Address) -> i128 {
+    match get_queued_withdrawal(env, provider) {
+        Some(queued) if queued.epoch == get_withdrawal_epoch(env) => queued.shares,
+        _ => 0,
+    }
+}
+
+/// Token amount a provider can claim, including burned shares not yet
+/// moved to their claimable balance
+pub fn claimable(env: &Env, provider: &Address) -> i128 {
+    let settled = get_queued_withdrawal(env, provider).and_then(|queued| {
+        let epoch = get_settled_epoch(env, queued.epoch)?;
+        Some(queued.shares * epoch.amount / epoch.shares)
+    });
+    get_claimable_withdrawal(env, provider) + settled.unwrap_or(0)
+}
+
+/// Price a provider's queued shares at their epoch's settled share price
+/// and add the amount to their claimable balance
+///
+/// Does nothing while the epoch is still open.
+fn settle_withdrawal(env: &Env, provider: &Address) {
+    let Some(queued) = get_queued_withdrawal(env, provider) else {
+        return;
+    };
+    let Some(epoch) = get_settled_epoch(env, queued.epoch) else {
+        return;
+    };
+    let amount = queued.shares * epoch.amount / epoch.shares;
+    remove_queued_withdrawal(env, provider);
+    set_claimable_withdrawal(env, provider, get_claimable_withdrawal(env, provider) + amount);
+
+    LiquidityWithdrawn {
+        provider: provider.clone(),
+        shares: queued.shares,
+        amount,
+    }
+    .publish(env);
+}
+
+/// Track a newly created round
+pub fn round_opened(env: &Env) {
+    set_open_rounds(env, get_open_rounds(env) + 1);
+}
+
+/// Track a round that ended or got cancelled
+///
+/// Once no round is open the bankroll is settled, so every share queued in
+/// the current withdrawal epoch is burned at that net asset value in one go
+/// and a new epoch starts. Providers collect their part with `claim`, so
+/// closing a round costs the same however many withdrawals are queued, and
+/// a provider that can't receive tokens can't block it.
+pub fn round_closed(env: &Env) {
+    let open_rounds = get_open_rounds(env).saturating_sub(1);
+    set_open_rounds(env, open_rounds);
+    if open_rounds > 0 {
+        return;
+    }
+
+    let shares = get_queued_shares(env);
+    if shares == 0 {
+        return;
+    }
+    let mut pool = get_pool(env);
+    let amount = shares * pool.bankroll / pool.total_shares;
+    pool.total_shares -= shares;
+    pool.bankroll -= amount;
+    set_pool(env, &pool);
+
+    let epoch = get_withdrawal_epoch(env);
+    set_settled_epoch(env, epoch, &SettledEpoch { shares, amount });
+    set_withdrawal_epoch(env, epoch + 1);
+    set_queued_shares(env, 0);
+}
+
+/// Pay out a provider's settled withdrawals
+pub fn claim(env: &Env, provider: &Address) -> Result<i128, Error> {
+    settle_withdrawal(env, provider);
+    let amount = get_claimable_withdrawal(env, provider);
+    if amount == 0 {
+        return Err(Error::NothingToClaim);
+    }
+    set_claimable_withdrawal(env, provider, 0);
+    transfer(env, &env.current_contract_address(), provider, amount)?;
+
+    WithdrawalClaimed {
+        provider: provider.clone(),
+        amount,
+    }
+    .publish(env);
+
+    Ok(amount)
+}
@@ -64,7 +64,7 @@ export const Errors = {
    */
   6: {message:"InvalidBetAmount"},
   /**
-   * Bet already placed for this round
+   * Player already placed the max number of bets in this round
    */
   7: {message:"BetAlreadyPlaced"},
   /**
@@ -90,33 +90,254 @@ export const Errors = {
   /**
    * Admin not initialized
    */
-  13: {message:"AdminNotInitialized"}
+  13: {message:"AdminNotInitialized"},
+  /**
+   * Round is neither cancelled nor timed out
+   */
+  14: {message:"RefundNotAvailable"},
+  /**
+   * Invalid configuration values
+   */
+  15: {message:"InvalidConfig"},
+  /**
+   * Bankroll can't cover the worst-case payout of the round
+   */
+  16: {message:"InsufficientBankroll"},
+  /**
+   * Invalid amount
+   */
+  17: {message:"InvalidAmount"},
+  /**
+   * Liquidity can't change while rounds are open
+   */
+  18: {message:"LiquidityLocked"},
+  /**
+   * Not enough LP shares
+   */
+  19: {message:"InsufficientShares"},
+  /**
+   * Contract is paused
+   */
+  20: {message:"ContractPaused"},
+  /**
+   * Contract is not paused
+   */
+  21: {message:"ContractNotPaused"},
+  /**
+   * Previous round is still waiting or in progress
+   */
+  22: {message:"PreviousRoundOpen"},
+  /**
+   * No seed chain committed
+   */
+  23: {message:"SeedChainNotFound"},
+  /**
+   * Every seed of the chain has been revealed
+   */
+  24: {message:"SeedChainExhausted"},
+  /**
+   * Bet has no seed commitment to reveal
+   */
+  25: {message:"SeedNotCommitted"},
+  /**
+   * Client seed doesn't match its commitment
+   */
+  26: {message:"InvalidClientSeed"},
+  /**
+   * Round stopped taking bets for the reveal phase
+   */
+  27: {message:"BettingClosed"},
+  /**
+   * Cash-out fraction must be between 1 and 10000 basis points
+   */
+  28: {message:"InvalidCashOutFraction"},
+  /**
+   * Bet reached the max number of partial cash-outs
+   */
+  29: {message:"TooManyPartialCashOuts"},
+  /**
+   * No settled withdrawal or unpaid payout to claim
+   */
+  30: {message:"NothingToClaim"},
+  /**
+   * Round hasn't reached its crash point yet
+   */
+  31: {message:"RoundNotCrashed"},
+  /**
+   * Round reached the max number of bets
+   */
+  32: {message:"RoundFull"},
+  /**
+   * Round has committed bets, betting must be closed for reveals first
+   */
+  33: {message:"RevealPhaseRequired"},
+  /**
+   * Reveal window of the round hasn't ended yet
+   */
+  34: {message:"RevealWindowOpen"},
+  /**
+   * Legacy round hasn't ended or legacy bet is still active
+   */
+  35: {message:"UnsettledLegacyEntry"},
+  /**
+   * No token stored and none passed to `migrate`
+   */
+  36: {message:"TokenNotInitialized"},
+  /**
+   * Round has client seeds, so the operator may already know its crash point
+   */
+  37: {message:"ClientSeedsCollected"},
+  /**
+   * Seed chain still has unrevealed seeds
+   */
+  38: {message:"SeedChainActive"}
 }
 
-export type RoundStatus = {tag: "Waiting", values: void} | {tag: "InProgress", values: void} | {tag: "Ended", values: void};
+/**
+ * Keys for persistent storage (and keyed instance entries such as roles)
+ * 
+ * Rounds and bets used to be stored under their bare `u64` id, which put
+ * both in the same keyspace. Tagging each id with its kind keeps them apart.
+ */
+export type DataKey = {tag: "Round", values: readonly [u64]} | {tag: "Bet", values: readonly [u64]} | {tag: "RoundBets", values: readonly [u64]} | {tag: "LpShares", values: readonly [string]} | {tag: "PendingWithdrawal", values: readonly [string]} | {tag: "ClaimableWithdrawal", values: readonly [string]} | {tag: "Role", values: readonly [Role]} | {tag: "PlayerBetCount", values: readonly [string]} | {tag: "PlayerBets", values: readonly [string, u32]} | {tag: "PlayerStats", values: readonly [string]} | {tag: "SeedCommitment", values: readonly [u64]} | {tag: "RevealedSeeds", values: readonly [u64]} | {tag: "RoundEntropy", values: readonly [u64]} | {tag: "PlayerRoundBets", values: readonly [string, u64]} | {tag: "WithdrawalEpoch", values: readonly [u32]} | {tag: "UnpaidPayout", values: readonly [string]} | {tag: "RoundChainLink", values: readonly [u64]};
+
+
+export type RoundStatus = {tag: "Waiting", values: void} | {tag: "Revealing", values: void} | {tag: "InProgress", values: void} | {tag: "Ended", values: void} | {tag: "Cancelled", values: void};
+
+
+/**
+ * Roles the admin can assign, each held by a single address
+ * 
+ * Kept in-house rather than on the workspace's `stellar-access`: its
+ * `AccessControl` grants `Symbol` roles to any number of accounts, managed
+ * by per-role admins, while each role here is one address that `get_role`
+ * returns and `set_role` replaces. Moving over would change both entry
+ * points and need a migration of the stored `DataKey::Role` entries.
+ */
+export type Role = {tag: "Operator", values: void} | {tag: "Treasurer", values: void} | {tag: "Pauser", values: void};
+
+
+/**
+ * Game parameters, updatable by the treasurer
+ */
+export interface Config {
+  house_edge_bps: u32;
+  in_progress_timeout: u64;
+  max_bet: i128;
+  max_bets_per_player: u32;
+  max_bets_per_round: u32;
+  max_client_seeds: u32;
+  max_exposure_bps: u32;
+  max_multiplier: u64;
+  min_bet: i128;
+  min_reveal_window: u64;
+  mix_ledger_entropy: boolean;
+  unrevealed_penalty_bps: u32;
+  waiting_timeout: u64;
+}
 
 
 export interface Round {
   bet_count: u32;
   client_seeds: Array<Buffer>;
+  committed_bets: u32;
   crash_multiplier: u64;
   created_at: u64;
   ended_at: u64;
+  house_edge_bps: u32;
+  house_profit: i128;
   id: u64;
+  in_progress_timeout: u64;
+  loser_count: u32;
+  max_multiplier: u64;
+  mix_ledger_entropy: boolean;
+  reveal_ends_at: u64;
+  server_seed: Option<Buffer>;
   server_seed_hash: Buffer;
   started_at: u64;
   status: RoundStatus;
   total_bet_amount: i128;
   total_payout: i128;
+  waiting_timeout: u64;
+  winner_count: u32;
+}
+
+/**
+ * Commitment to a SHA-256 chain of server seeds
+ * 
+ * The operator draws a random `seed_n`, computes `seed_{i-1} = sha256(seed_i)`
+ * down to `seed_1` and commits `terminal_hash = sha256(seed_1)`. Rounds then
+ * reveal `seed_1`, `seed_2`, ... in order, each hashing to the link revealed
+ * before it.
+ */
+export interface SeedChain {
+  head: Buffer;
+  length: u32;
+  revealed: u32;
+  terminal_hash: Buffer;
+}
+
+/**
+ * Data drawn when a round starts, which the operator can't know when
+ * committing to the server seed
+ */
+export interface RoundEntropy {
+  entropy: Buffer;
+  ledger_sequence: u32;
+  ledger_timestamp: u64;
+  prng_seed: Buffer;
+}
+
+/**
+ * Position of a round's server seed in the seed chain it was revealed from
+ */
+export interface SeedChainLink {
+  length: u32;
+  position: u32;
+  terminal_hash: Buffer;
+}
+
+/**
+ * Seed chain a round's server seed was revealed from, if any
+ */
+export type ChainSource = {tag: "None", values: void} | {tag: "Link", values: readonly [SeedChainLink]};
+
+
+/**
+ * Extra data mixed into a round's hash besides the seeds
+ */
+export type EntropySource = {tag: "None", values: void} | {tag: "Ledger", values: readonly [RoundEntropy]};
+
+
+/**
+ * Result of recomputing a round's provably fair outcome from stored seeds
+ */
+export interface RoundVerification {
+  client_seeds: Array<Buffer>;
+  crash_multiplier: u64;
+  crash_multiplier_valid: boolean;
+  entropy: EntropySource;
+  formula: string;
+  recorded_crash_multiplier: u64;
+  round_hash: Buffer;
+  round_id: u64;
+  seed_chain: ChainSource;
+  seed_hash_valid: boolean;
+  server_seed: Buffer;
+  server_seed_hash: Buffer;
+  verified: boolean;
 }
 
-export type BetStatus = {tag: "Active", values: void} | {tag: "CashedOut", values: void} | {tag: "Lost", values: void};
+export type BetStatus = {tag: "Active", values: void} | {tag: "CashedOut", values: void} | {tag: "Lost", values: void} | {tag: "Refunded", values: void} | {tag: "Forfeited", values: void};
+
 
 
 export interface Bet {
   amount: i128;
+  auto_cash_out: Option<u64>;
   cash_out_multiplier: u64;
   id: u64;
+  partial_cash_outs: Array<PartialCashOut>;
   payout: i128;
   player: string;
   round_id: u64;
@@ -125,7 +346,179 @@ export interface Bet {
 }
 
 
+export interface PartialCashOut {
+  amount: i128;
+  multiplier: u64;
+  payout: i128;
+  timestamp: u64;
+}
+
+
+export interface PlayerStats {
+  biggest_multiplier: u64;
+  biggest_payout: i128;
+  last_played: u64;
+  net_pnl: i128;
+  rounds_played: u32;
+  total_wagered: i128;
+  total_won: i128;
+}
+
+
 export interface Pool {
+  bankroll: i128;
+  total_bets: i128;
+  total_house_earnings: i128;
+  total_payouts: i128;
+  total_shares: i128;
+}
+
+/**
+ * LP shares a provider queued for withdrawal
+ */
+export interface QueuedWithdrawal {
+  epoch: u32;
+  shares: i128;
+}
+
+/**
+ * Settled share price of a withdrawal epoch
+ * 
+ * All shares queued in the epoch are burned together when the last open
+ * round closes. Each provider then gets `shares * amount / total shares`.
+ */
+export interface SettledEpoch {
+  amount: i128;
+  shares: i128;
+}
+
+/**
+ * Round layout of schema version 1, before seeds, config snapshots and
+ * settlement stats were stored on the round
+ */
+export interface RoundV1 {
+  bet_count: u32;
+  client_seeds: Array<Buffer>;
+  crash_multiplier: u64;
+  created_at: u64;
+  ended_at: u64;
+  id: u64;
+  server_seed_hash: Buffer;
+  started_at: u64;
+  status: RoundStatus;
+  total_bet_amount: i128;
+  total_payout: i128;
+}
+
+/**
+ * Round layout of schema versions 2 to 7, before the ledger entropy switch
+ * was stored on the round
+ */
+export interface RoundV2 {
+  bet_count: u32;
+  client_seeds: Array<Buffer>;
+  crash_multiplier: u64;
+  created_at: u64;
+  ended_at: u64;
+  house_edge_bps: u32;
+  house_profit: i128;
+  id: u64;
+  loser_count: u32;
+  max_multiplier: u64;
+  server_seed: Option<Buffer>;
+  server_seed_hash: Buffer;
+  started_at: u64;
+  status: RoundStatus;
+  total_bet_amount: i128;
+  total_payout: i128;
+  winner_count: u32;
+}
+
+/**
+ * Round layout of schema versions 8 and 9, before the reveal phase was
+ * tracked on the round
+ */
+export interface RoundV3 {
+  bet_count: u32;
+  client_seeds: Array<Buffer>;
+  crash_multiplier: u64;
+  created_at: u64;
+  ended_at: u64;
+  house_edge_bps: u32;
+  house_profit: i128;
+  id: u64;
+  loser_count: u32;
+  max_multiplier: u64;
+  mix_ledger_entropy: boolean;
+  server_seed: Option<Buffer>;
+  server_seed_hash: Buffer;
+  started_at: u64;
+  status: RoundStatus;
+  total_bet_amount: i128;
+  total_payout: i128;
+  winner_count: u32;
+}
+
+/**
+ * Round layout of schema version 10, before the refund timeouts were
+ * stored on the round
+ */
+export interface RoundV4 {
+  bet_count: u32;
+  client_seeds: Array<Buffer>;
+  committed_bets: u32;
+  crash_multiplier: u64;
+  created_at: u64;
+  ended_at: u64;
+  house_edge_bps: u32;
+  house_profit: i128;
+  id: u64;
+  loser_count: u32;
+  max_multiplier: u64;
+  mix_ledger_entropy: boolean;
+  reveal_ends_at: u64;
+  server_seed: Option<Buffer>;
+  server_seed_hash: Buffer;
+  started_at: u64;
+  status: RoundStatus;
+  total_bet_amount: i128;
+  total_payout: i128;
+  winner_count: u32;
+}
+
+/**
+ * Bet layout of schema version 1, before auto cash-out
+ */
+export interface BetV1 {
+  amount: i128;
+  cash_out_multiplier: u64;
+  id: u64;
+  payout: i128;
+  player: string;
+  round_id: u64;
+  status: BetStatus;
+  timestamp: u64;
+}
+
+/**
+ * Bet layout of schema versions 2 to 5, before partial cash-outs
+ */
+export interface BetV2 {
+  amount: i128;
+  auto_cash_out: Option<u64>;
+  cash_out_multiplier: u64;
+  id: u64;
+  payout: i128;
+  player: string;
+  round_id: u64;
+  status: BetStatus;
+  timestamp: u64;
+}
+
+/**
+ * Pool layout of schema version 1, before the bankroll and LP shares
+ */
+export interface PoolV1 {
   total_bets: i128;
   total_house_earnings: i128;
   total_payouts: i128;
@@ -154,12 +547,14 @@ export interface Client {
   }) => Promise<AssembledTransaction<string>>
 
   /**
-   * Construct and simulate a create_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Create a new round (admin only)
+   * Construct and simulate a propose_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose a new admin (admin only)
    * 
-   * Security: Only admin can create rounds to prevent spam
+   * The transfer only happens once the new admin calls `accept_admin`,
+   * so a typo can't lock the contract. Proposing again replaces the
+   * previous proposal.
    */
-  create_round: ({round_id, server_seed_hash}: {round_id: u64, server_seed_hash: Buffer}, options?: {
+  propose_admin: ({new_admin}: {new_admin: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -174,15 +569,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<Round>>>
+  }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a start_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start the round with server seed (admin only)
-   * 
-   * Security: Server seed must match hash, multiplier pre-determined
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept a pending admin transfer (proposed admin only)
    */
-  start_round: ({round_id, server_seed, crash_multiplier}: {round_id: u64, server_seed: Buffer, crash_multiplier: u64}, options?: {
+  accept_admin: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -200,16 +593,10 @@ export interface Client {
   }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a place_bet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Place a bet in the current round
-   * 
-   * Security:
-   * - Checks player balance
-   * - Validates bet amount (min/max)
-   * - Prevents betting after round started
-   * - Uses token transfer for XLM
+   * Construct and simulate a pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get admin address waiting to accept the transfer, if any
    */
-  place_bet: ({player, round_id, amount, client_seed}: {player: string, round_id: u64, amount: i128, client_seed: Buffer}, options?: {
+  pending_admin: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -224,20 +611,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<u64>>>
+  }) => Promise<AssembledTransaction<Option<string>>>
 
   /**
-   * Construct and simulate a cash_out transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Cash out a bet at current multiplier
-   * 
-   * Security:
-   * - Verifies bet ownership
-   * - Checks bet is active
-   * - Validates multiplier hasn't crashed
-   * - Calculates payout with house edge
-   * - Prevents re-entry
+   * Construct and simulate a set_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Assign a role to an address (admin only)
    */
-  cash_out: ({player, bet_id, current_multiplier}: {player: string, bet_id: u64, current_multiplier: u64}, options?: {
+  set_role: ({role, account}: {role: Role, account: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -252,19 +632,39 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<i128>>>
+  }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a finalize_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Finalize the round (admin only)
+   * Construct and simulate a get_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get address holding a role
+   */
+  get_role: ({role}: {role: Role}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pause the contract (pauser only)
    * 
-   * Security:
-   * - Only admin can finalize
-   * - Verifies round is in progress
-   * - Marks all uncashed bets as lost
-   * - Records final stats
+   * Blocks new rounds, bets and liquidity deposits, and moving a round to
+   * its reveal phase or start. Cash-outs, seed reveals, finalization,
+   * refunds, claims, withdrawals and views keep working so players and
+   * liquidity providers can always exit.
    */
-  finalize_round: ({round_id}: {round_id: u64}, options?: {
+  pause: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -282,10 +682,10 @@ export interface Client {
   }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get round details
+   * Construct and simulate a unpause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Unpause the contract (pauser only)
    */
-  get_round: ({round_id}: {round_id: u64}, options?: {
+  unpause: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -300,13 +700,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<Round>>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_bet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get bet details
+   * Construct and simulate a paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check if the contract is paused
    */
-  get_bet: ({bet_id}: {bet_id: u64}, options?: {
+  paused: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -321,7 +721,1024 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<Bet>>>
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get token contract address used for bets and payouts
+   */
+  token: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a set_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update game configuration (treasurer only)
+   * 
+   * Rounds keep the house edge and max multiplier they were created with.
+   */
+  set_config: ({config}: {config: Config}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get game configuration
+   */
+  get_config: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Config>>
+
+  /**
+   * Construct and simulate a deposit_liquidity transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Deposit liquidity into the house bankroll
+   * 
+   * Mints LP shares priced at the bankroll's net asset value, so every
+   * provider shares the house P&L in proportion to their shares. Locked
+   * while rounds are open, since their outcome isn't settled yet, and
+   * while the contract is paused.
+   */
+  deposit_liquidity: ({provider, amount}: {provider: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a withdraw_liquidity transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw liquidity by burning LP shares
+   * 
+   * Paid out right away when no round is open. Otherwise the shares are
+   * queued and burned once the last open round is finalized or cancelled,
+   * at the settled net asset value; returns 0 in that case and the amount
+   * is then collected with `claim_withdrawal`.
+   */
+  withdraw_liquidity: ({provider, shares}: {provider: string, shares: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a claim_withdrawal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pay out a provider's settled withdrawals
+   * 
+   * Queued withdrawals become claimable once the last open round closes.
+   * Returns the amount transferred.
+   */
+  claim_withdrawal: ({provider}: {provider: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a get_share_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get price of one LP share in token units, scaled by 10^7
+   */
+  get_share_price: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_lp_shares transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get LP shares held by a provider
+   */
+  get_lp_shares: ({provider}: {provider: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_pending_withdrawal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get LP shares a provider queued for withdrawal
+   */
+  get_pending_withdrawal: ({provider}: {provider: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_claimable_withdrawal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get token amount a provider can claim with `claim_withdrawal`
+   */
+  get_claimable_withdrawal: ({provider}: {provider: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a claim_payout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pay out tokens credited to a player
+   * 
+   * Refunds of forfeited bets are credited instead of transferred, and so
+   * are auto cash-outs that couldn't be transferred at finalization.
+   * Returns the amount transferred.
+   */
+  claim_payout: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a get_unpaid_payout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get tokens credited to a player, collected with `claim_payout`
+   */
+  get_unpaid_payout: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a create_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Create the next round (operator only)
+   * 
+   * Security: Only the operator can create rounds to prevent spam
+   * 
+   * Round ids are sequential, starting at 1. A new round can only be
+   * created once the previous one is finalized or cancelled. While a seed
+   * chain has unrevealed seeds, rounds must come from
+   * `create_chained_round` so the chain's history has no gaps.
+   */
+  create_round: ({server_seed_hash}: {server_seed_hash: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Round>>>
+
+  /**
+   * Construct and simulate a commit_seed_chain transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Commit to a chain of `length` server seeds (operator only)
+   * 
+   * Rounds created with `create_chained_round` then use the chain's links
+   * as commitments, so no fresh hash is needed per round and the whole
+   * history is linked. A previous chain can only be replaced once all its
+   * seeds are revealed.
+   */
+  commit_seed_chain: ({terminal_hash, length}: {terminal_hash: Buffer, length: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_seed_chain transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get committed seed chain, if any
+   */
+  get_seed_chain: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<SeedChain>>>
+
+  /**
+   * Construct and simulate a create_chained_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Create the next round committed to the next link of the seed chain
+   * (operator only)
+   * 
+   * The round's `server_seed_hash` is the last revealed link, so
+   * `start_round` must reveal its preimage. A round cancelled before it
+   * starts reveals nothing and the next round reuses the same link.
+   */
+  create_chained_round: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Round>>>
+
+  /**
+   * Construct and simulate a close_betting transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stop taking bets and open the reveal phase (operator only)
+   * 
+   * Committed client seeds can only be revealed after this, so nobody can
+   * pick a seed after seeing the revealed ones. The round can't start for
+   * `min_reveal_window` seconds, giving players time to reveal. Bets still
+   * unrevealed when the round starts are forfeited.
+   */
+  close_betting: ({round_id}: {round_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start the round with server seed (operator only)
+   * 
+   * Security:
+   * - Server seed must match the committed hash
+   * - Crash multiplier is derived on-chain from the server seed and the
+   * collected client seeds, so nobody can pick it
+   * - Revealed server seed is stored for verification
+   */
+  start_round: ({round_id, server_seed}: {round_id: u64, server_seed: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a place_bet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Place a bet in the current round
+   * 
+   * `auto_cash_out` optionally sets a target multiplier (100 = 1.00x): if
+   * the round reaches it, the bet is cashed out at the target without the
+   * player sending another transaction.
+   * 
+   * Security:
+   * - Checks player balance
+   * - Validates bet amount (min/max)
+   * - Checks the bankroll can cover the round's worst-case payout
+   * - Prevents betting after round started
+   * - Uses token transfer for XLM
+   */
+  place_bet: ({player, round_id, amount, client_seed, auto_cash_out}: {player: string, round_id: u64, amount: i128, client_seed: Buffer, auto_cash_out: Option<u64>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a place_committed_bet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Place a bet with a commitment to its client seed instead of the seed
+   * 
+   * `seed_commitment` is `sha256(client_seed)`. The player reveals the
+   * seed with `reveal_client_seed` before the round starts; every revealed
+   * seed is mixed into the crash point. Bets still unrevealed when the
+   * round starts are voided and refunded minus `unrevealed_penalty_bps`.
+   */
+  place_committed_bet: ({player, round_id, amount, seed_commitment, auto_cash_out}: {player: string, round_id: u64, amount: i128, seed_commitment: Buffer, auto_cash_out: Option<u64>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a reveal_client_seed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the client seed of a committed bet
+   * 
+   * Only accepted once the operator closed betting with `close_betting`.
+   */
+  reveal_client_seed: ({player, bet_id, client_seed}: {player: string, bet_id: u64, client_seed: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a cash_out transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cash out a bet at current multiplier
+   * 
+   * Security:
+   * - Verifies bet ownership
+   * - Checks bet is active
+   * - Computes the live multiplier on-chain from the ledger timestamp
+   * - Validates multiplier hasn't crashed
+   * - Calculates payout with house edge
+   * - Prevents re-entry
+   */
+  cash_out: ({player, bet_id}: {player: string, bet_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a cash_out_partial transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cash out part of a bet, leaving the rest of the stake riding
+   * 
+   * `fraction_bps` is the share of the stake still riding to settle at the
+   * live multiplier; 10000 settles the whole bet like `cash_out`. Each
+   * partial settlement is recorded on the bet, up to
+   * `MAX_PARTIAL_CASH_OUTS`. Returns the net payout.
+   */
+  cash_out_partial: ({player, bet_id, fraction_bps}: {player: string, bet_id: u64, fraction_bps: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a finalize_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Finalize the round (operator only)
+   * 
+   * Security:
+   * - Only the operator can finalize
+   * - Verifies round is in progress and the curve reached the crash point
+   * - Cashes out bets whose auto cash-out target was reached; a payout
+   * that can't be transferred is credited for `claim_payout` instead
+   * - Marks all uncashed bets as lost, keeping their partial cash-outs
+   * - Records final stats (total payout, winners, losers, house profit)
+   */
+  finalize_round: ({round_id}: {round_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a migrate_storage transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Migrate rounds and bets stored under bare `u64` ids (admin only)
+   * 
+   * Kept for existing tooling, same as `migrate` without a token, which
+   * also rewrites the moved entries in the current layout.
+   */
+  migrate_storage: ({ids}: {ids: Array<u64>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Replace the contract code, keeping all storage (admin only)
+   * 
+   * The new code runs from the next invocation. If it changes the storage
+   * layout, call `migrate` right after.
+   */
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Bring storage to the current schema version (admin only)
+   * 
+   * Moves entries under bare `u64` ids to their typed keys, rewrites
+   * rounds and bets stored in older layouts and upgrades the pool. Large
+   * deployments can pass the ids in batches; every call is idempotent.
+   * Returns how many rounds and bets were rewritten.
+   * 
+   * Version 1 deployments only stored the admin and the pool: `token`
+   * sets the bet token if none is stored yet (it is ignored otherwise),
+   * and unassigned roles go to the admin, as in the constructor. Legacy
+   * rounds must have ended and legacy bets must be settled.
+   */
+  migrate: ({ids, token}: {ids: Array<u64>, token: Option<string>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get storage schema version
+   */
+  version: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a bump_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Extend the TTL of a round and its bets (anyone can call)
+   * 
+   * Keepers call this for rounds that are still open or have refunds
+   * to claim. Ended rounds get a fixed TTL when finalized and can't be
+   * bumped, so they expire on purpose.
+   */
+  bump_round: ({round_id}: {round_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a current_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the latest round if it is still waiting or in progress
+   */
+  current_round: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<Round>>>
+
+  /**
+   * Construct and simulate a get_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get round details
+   */
+  get_round: ({round_id}: {round_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Round>>>
+
+  /**
+   * Construct and simulate a cancel_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancel a round that hasn't started (operator only)
+   * 
+   * Every active bet of a cancelled round can be refunded with `claim_refund`.
+   * The operator knows the server seed, so once client seeds are collected
+   * they can compute the crash point and would only cancel rounds they'd
+   * lose. Cancelling is therefore limited to rounds without client seeds,
+   * or rounds whose crash point also mixes in ledger entropy drawn at
+   * start. Other rounds, and rounds in progress, can only be cancelled
+   * through the timeouts of `claim_refund`.
+   */
+  cancel_round: ({round_id}: {round_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a claim_refund transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Refund the stake of an active bet (anyone can call)
+   * 
+   * Available once the round is cancelled, or when it is stuck: not started
+   * `waiting_timeout` seconds after creation or not finalized
+   * `in_progress_timeout` seconds after start, as configured when the round
+   * was created. A stuck round is cancelled by the first refund so it can't
+   * be resumed afterwards. The stake always goes back to the bet's player,
+   * minus any part already settled by partial cash-outs; returns the
+   * refunded amount.
+   */
+  claim_refund: ({bet_id}: {bet_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a verify_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Verify a round's provably fair outcome
+   * 
+   * Recomputes the seed hash and the crash multiplier from the stored seeds
+   * so verifiers don't have to trust the frontend.
+   */
+  verify_round: ({round_id}: {round_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<RoundVerification>>>
+
+  /**
+   * Construct and simulate a get_multiplier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the live multiplier of a round in progress (100 = 1.00x)
+   * 
+   * Follows the published growth curve and stops at the crash point.
+   */
+  get_multiplier: ({round_id}: {round_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a get_bet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get bet details
+   */
+  get_bet: ({bet_id}: {bet_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Bet>>>
+
+  /**
+   * Construct and simulate a get_round_bets transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a page of the bets placed in a round, in placement order
+   * 
+   * `limit` is capped at `MAX_PAGE_SIZE`.
+   */
+  get_round_bets: ({round_id, cursor, limit}: {round_id: u64, cursor: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Array<Bet>>>>
+
+  /**
+   * Construct and simulate a get_round_cashouts transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the bets cashed out in a round, highest payout first
+   * 
+   * Bets with the same payout keep the order they were placed in.
+   */
+  get_round_cashouts: ({round_id}: {round_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Array<Bet>>>>
+
+  /**
+   * Construct and simulate a get_player_round_bets transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the bets a player placed in a round, in placement order
+   */
+  get_player_round_bets: ({player, round_id}: {player: string, round_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<Bet>>>
+
+  /**
+   * Construct and simulate a get_player_bets transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a page of a player's bets, oldest first
+   * 
+   * `cursor` is the position in the player's history to start from and
+   * `limit` is capped at `MAX_PAGE_SIZE`. Use `get_player_bet_count` to
+   * page from the most recent bets backwards.
+   */
+  get_player_bets: ({player, cursor, limit}: {player: string, cursor: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<Bet>>>
+
+  /**
+   * Construct and simulate a get_player_bet_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get number of bets a player placed
+   */
+  get_player_bet_count: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_player_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get lifetime stats of a player
+   */
+  get_player_stats: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<PlayerStats>>
 
   /**
    * Construct and simulate a get_pool transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -348,7 +1765,7 @@ export interface Client {
 export class Client extends ContractClient {
   static async deploy<T = Client>(
         /** Constructor/Initialization Args for the contract's `__constructor` method */
-        {admin}: {admin: string},
+        {admin, token}: {admin: string, token: string},
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
       Omit<ContractClientOptions, "contractId"> & {
@@ -360,38 +1777,169 @@ export class Client extends ContractClient {
         format?: "hex" | "base64";
       }
   ): Promise<AssembledTransaction<T>> {
-    return ContractClient.deploy({admin}, options)
+    return ContractClient.deploy({admin, token}, options)
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAADQAAAA9Sb3VuZCBub3QgZm91bmQAAAAADVJvdW5kTm90Rm91bmQAAAAAAAABAAAAFFJvdW5kIGFscmVhZHkgZXhpc3RzAAAAElJvdW5kQWxyZWFkeUV4aXN0cwAAAAAAAgAAACdJbnZhbGlkIHJvdW5kIHN0YXR1cyBmb3IgdGhpcyBvcGVyYXRpb24AAAAAEkludmFsaWRSb3VuZFN0YXR1cwAAAAAAAwAAABhJbnZhbGlkIHNlcnZlciBzZWVkIGhhc2gAAAAVSW52YWxpZFNlcnZlclNlZWRIYXNoAAAAAAAABAAAABhJbnZhbGlkIG11bHRpcGxpZXIgdmFsdWUAAAARSW52YWxpZE11bHRpcGxpZXIAAAAAAAAFAAAAEkludmFsaWQgYmV0IGFtb3VudAAAAAAAEEludmFsaWRCZXRBbW91bnQAAAAGAAAAIUJldCBhbHJlYWR5IHBsYWNlZCBmb3IgdGhpcyByb3VuZAAAAAAAABBCZXRBbHJlYWR5UGxhY2VkAAAABwAAAA1CZXQgbm90IGZvdW5kAAAAAAAAC0JldE5vdEZvdW5kAAAAAAgAAAARQmV0IGlzIG5vdCBhY3RpdmUAAAAAAAAMQmV0Tm90QWN0aXZlAAAACQAAABZVbmF1dGhvcml6ZWQgb3BlcmF0aW9uAAAAAAAMVW5hdXRob3JpemVkAAAACgAAABVSb3VuZCBhbHJlYWR5IGNyYXNoZWQAAAAAAAAOQWxyZWFkeUNyYXNoZWQAAAAAAAsAAAAPVHJhbnNmZXIgZmFpbGVkAAAAAA5UcmFuc2ZlckZhaWxlZAAAAAAADAAAABVBZG1pbiBub3QgaW5pdGlhbGl6ZWQAAAAAAAATQWRtaW5Ob3RJbml0aWFsaXplZAAAAAAN",
-        "AAAAAgAAAAAAAAAAAAAAC1JvdW5kU3RhdHVzAAAAAAMAAAAAAAAAAAAAAAdXYWl0aW5nAAAAAAAAAAAAAAAACkluUHJvZ3Jlc3MAAAAAAAAAAAAAAAAABUVuZGVkAAAA",
-        "AAAAAQAAAAAAAAAAAAAABVJvdW5kAAAAAAAACwAAAAAAAAAJYmV0X2NvdW50AAAAAAAABAAAAAAAAAAMY2xpZW50X3NlZWRzAAAD6gAAA+4AAAAgAAAAAAAAABBjcmFzaF9tdWx0aXBsaWVyAAAABgAAAAAAAAAKY3JlYXRlZF9hdAAAAAAABgAAAAAAAAAIZW5kZWRfYXQAAAAGAAAAAAAAAAJpZAAAAAAABgAAAAAAAAAQc2VydmVyX3NlZWRfaGFzaAAAA+4AAAAgAAAAAAAAAApzdGFydGVkX2F0AAAAAAAGAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAALUm91bmRTdGF0dXMAAAAAAAAAABB0b3RhbF9iZXRfYW1vdW50AAAACwAAAAAAAAAMdG90YWxfcGF5b3V0AAAACw==",
-        "AAAAAgAAAAAAAAAAAAAACUJldFN0YXR1cwAAAAAAAAMAAAAAAAAAAAAAAAZBY3RpdmUAAAAAAAAAAAAAAAAACUNhc2hlZE91dAAAAAAAAAAAAAAAAAAABExvc3Q=",
-        "AAAAAQAAAAAAAAAAAAAAA0JldAAAAAAIAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAE2Nhc2hfb3V0X211bHRpcGxpZXIAAAAABgAAAAAAAAACaWQAAAAAAAYAAAAAAAAABnBheW91dAAAAAAACwAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAhyb3VuZF9pZAAAAAYAAAAAAAAABnN0YXR1cwAAAAAH0AAAAAlCZXRTdGF0dXMAAAAAAAAAAAAACXRpbWVzdGFtcAAAAAAAAAY=",
-        "AAAAAQAAAAAAAAAAAAAABFBvb2wAAAADAAAAAAAAAAp0b3RhbF9iZXRzAAAAAAALAAAAAAAAABR0b3RhbF9ob3VzZV9lYXJuaW5ncwAAAAsAAAAAAAAADXRvdGFsX3BheW91dHMAAAAAAAAL",
-        "AAAAAAAAACpJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIGFkbWluIGFkZHJlc3MAAAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAQAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAA==",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAJgAAAA9Sb3VuZCBub3QgZm91bmQAAAAADVJvdW5kTm90Rm91bmQAAAAAAAABAAAAFFJvdW5kIGFscmVhZHkgZXhpc3RzAAAAElJvdW5kQWxyZWFkeUV4aXN0cwAAAAAAAgAAACdJbnZhbGlkIHJvdW5kIHN0YXR1cyBmb3IgdGhpcyBvcGVyYXRpb24AAAAAEkludmFsaWRSb3VuZFN0YXR1cwAAAAAAAwAAABhJbnZhbGlkIHNlcnZlciBzZWVkIGhhc2gAAAAVSW52YWxpZFNlcnZlclNlZWRIYXNoAAAAAAAABAAAABhJbnZhbGlkIG11bHRpcGxpZXIgdmFsdWUAAAARSW52YWxpZE11bHRpcGxpZXIAAAAAAAAFAAAAEkludmFsaWQgYmV0IGFtb3VudAAAAAAAEEludmFsaWRCZXRBbW91bnQAAAAGAAAAOlBsYXllciBhbHJlYWR5IHBsYWNlZCB0aGUgbWF4IG51bWJlciBvZiBiZXRzIGluIHRoaXMgcm91bmQAAAAAABBCZXRBbHJlYWR5UGxhY2VkAAAABwAAAA1CZXQgbm90IGZvdW5kAAAAAAAAC0JldE5vdEZvdW5kAAAAAAgAAAARQmV0IGlzIG5vdCBhY3RpdmUAAAAAAAAMQmV0Tm90QWN0aXZlAAAACQAAABZVbmF1dGhvcml6ZWQgb3BlcmF0aW9uAAAAAAAMVW5hdXRob3JpemVkAAAACgAAABVSb3VuZCBhbHJlYWR5IGNyYXNoZWQAAAAAAAAOQWxyZWFkeUNyYXNoZWQAAAAAAAsAAAAPVHJhbnNmZXIgZmFpbGVkAAAAAA5UcmFuc2ZlckZhaWxlZAAAAAAADAAAABVBZG1pbiBub3QgaW5pdGlhbGl6ZWQAAAAAAAATQWRtaW5Ob3RJbml0aWFsaXplZAAAAAANAAAAKFJvdW5kIGlzIG5laXRoZXIgY2FuY2VsbGVkIG5vciB0aW1lZCBvdXQAAAASUmVmdW5kTm90QXZhaWxhYmxlAAAAAAAOAAAAHEludmFsaWQgY29uZmlndXJhdGlvbiB2YWx1ZXMAAAANSW52YWxpZENvbmZpZwAAAAAAAA8AAAA3QmFua3JvbGwgY2FuJ3QgY292ZXIgdGhlIHdvcnN0LWNhc2UgcGF5b3V0IG9mIHRoZSByb3VuZAAAAAAUSW5zdWZmaWNpZW50QmFua3JvbGwAAAAQAAAADkludmFsaWQgYW1vdW50AAAAAAANSW52YWxpZEFtb3VudAAAAAAAABEAAAAsTGlxdWlkaXR5IGNhbid0IGNoYW5nZSB3aGlsZSByb3VuZHMgYXJlIG9wZW4AAAAPTGlxdWlkaXR5TG9ja2VkAAAAABIAAAAUTm90IGVub3VnaCBMUCBzaGFyZXMAAAASSW5zdWZmaWNpZW50U2hhcmVzAAAAAAATAAAAEkNvbnRyYWN0IGlzIHBhdXNlZAAAAAAADkNvbnRyYWN0UGF1c2VkAAAAAAAUAAAAFkNvbnRyYWN0IGlzIG5vdCBwYXVzZWQAAAAAABFDb250cmFjdE5vdFBhdXNlZAAAAAAAABUAAAAuUHJldmlvdXMgcm91bmQgaXMgc3RpbGwgd2FpdGluZyBvciBpbiBwcm9ncmVzcwAAAAAAEVByZXZpb3VzUm91bmRPcGVuAAAAAAAAFgAAABdObyBzZWVkIGNoYWluIGNvbW1pdHRlZAAAAAARU2VlZENoYWluTm90Rm91bmQAAAAAAAAXAAAAKUV2ZXJ5IHNlZWQgb2YgdGhlIGNoYWluIGhhcyBiZWVuIHJldmVhbGVkAAAAAAAAElNlZWRDaGFpbkV4aGF1c3RlZAAAAAAAGAAAACRCZXQgaGFzIG5vIHNlZWQgY29tbWl0bWVudCB0byByZXZlYWwAAAAQU2VlZE5vdENvbW1pdHRlZAAAABkAAAAoQ2xpZW50IHNlZWQgZG9lc24ndCBtYXRjaCBpdHMgY29tbWl0bWVudAAAABFJbnZhbGlkQ2xpZW50U2VlZAAAAAAAABoAAAAuUm91bmQgc3RvcHBlZCB0YWtpbmcgYmV0cyBmb3IgdGhlIHJldmVhbCBwaGFzZQAAAAAADUJldHRpbmdDbG9zZWQAAAAAAAAbAAAAOkNhc2gtb3V0IGZyYWN0aW9uIG11c3QgYmUgYmV0d2VlbiAxIGFuZCAxMDAwMCBiYXNpcyBwb2ludHMAAAAAABZJbnZhbGlkQ2FzaE91dEZyYWN0aW9uAAAAAAAcAAAAL0JldCByZWFjaGVkIHRoZSBtYXggbnVtYmVyIG9mIHBhcnRpYWwgY2FzaC1vdXRzAAAAABZUb29NYW55UGFydGlhbENhc2hPdXRzAAAAAAAdAAAAL05vIHNldHRsZWQgd2l0aGRyYXdhbCBvciB1bnBhaWQgcGF5b3V0IHRvIGNsYWltAAAAAA5Ob3RoaW5nVG9DbGFpbQAAAAAAHgAAAChSb3VuZCBoYXNuJ3QgcmVhY2hlZCBpdHMgY3Jhc2ggcG9pbnQgeWV0AAAAD1JvdW5kTm90Q3Jhc2hlZAAAAAAfAAAAJFJvdW5kIHJlYWNoZWQgdGhlIG1heCBudW1iZXIgb2YgYmV0cwAAAAlSb3VuZEZ1bGwAAAAAAAAgAAAAQlJvdW5kIGhhcyBjb21taXR0ZWQgYmV0cywgYmV0dGluZyBtdXN0IGJlIGNsb3NlZCBmb3IgcmV2ZWFscyBmaXJzdAAAAAAAE1JldmVhbFBoYXNlUmVxdWlyZWQAAAAAIQAAACtSZXZlYWwgd2luZG93IG9mIHRoZSByb3VuZCBoYXNuJ3QgZW5kZWQgeWV0AAAAABBSZXZlYWxXaW5kb3dPcGVuAAAAIgAAADdMZWdhY3kgcm91bmQgaGFzbid0IGVuZGVkIG9yIGxlZ2FjeSBiZXQgaXMgc3RpbGwgYWN0aXZlAAAAABRVbnNldHRsZWRMZWdhY3lFbnRyeQAAACMAAAAsTm8gdG9rZW4gc3RvcmVkIGFuZCBub25lIHBhc3NlZCB0byBgbWlncmF0ZWAAAAATVG9rZW5Ob3RJbml0aWFsaXplZAAAAAAkAAAASFJvdW5kIGhhcyBjbGllbnQgc2VlZHMsIHNvIHRoZSBvcGVyYXRvciBtYXkgYWxyZWFkeSBrbm93IGl0cyBjcmFzaCBwb2ludAAAABRDbGllbnRTZWVkc0NvbGxlY3RlZAAAACUAAAAlU2VlZCBjaGFpbiBzdGlsbCBoYXMgdW5yZXZlYWxlZCBzZWVkcwAAAAAAAA9TZWVkQ2hhaW5BY3RpdmUAAAAAJg==",
+        "AAAABQAAAEZFbWl0dGVkIHdoZW4gYSByb3VuZCBpcyBjcmVhdGVkLCB0b3BpY3M6IGBbInJvdW5kX2NyZWF0ZWQiLCByb3VuZF9pZF1gAAAAAAAAAAAADFJvdW5kQ3JlYXRlZAAAAAEAAAANcm91bmRfY3JlYXRlZAAAAAAAAAIAAAAAAAAACHJvdW5kX2lkAAAABgAAAAEAAAAAAAAAEHNlcnZlcl9zZWVkX2hhc2gAAAPuAAAAIAAAAAAAAAAC",
+        "AAAABQAAAFJFbWl0dGVkIHdoZW4gdGhlIG9wZXJhdG9yIGNvbW1pdHMgYSBzZWVkIGNoYWluLAp0b3BpY3M6IGBbInNlZWRfY2hhaW5fY29tbWl0dGVkIl1gAAAAAAAAAAAAElNlZWRDaGFpbkNvbW1pdHRlZAAAAAAAAQAAABRzZWVkX2NoYWluX2NvbW1pdHRlZAAAAAIAAAAAAAAADXRlcm1pbmFsX2hhc2gAAAAAAAPuAAAAIAAAAAAAAAAAAAAABmxlbmd0aAAAAAAABAAAAAAAAAAC",
+        "AAAABQAAAEJFbWl0dGVkIHdoZW4gYSByb3VuZCBzdGFydHMsIHRvcGljczogYFsicm91bmRfc3RhcnRlZCIsIHJvdW5kX2lkXWAAAAAAAAAAAAAMUm91bmRTdGFydGVkAAAAAQAAAA1yb3VuZF9zdGFydGVkAAAAAAAAAwAAAAAAAAAIcm91bmRfaWQAAAAGAAAAAQAAAAAAAAALc2VydmVyX3NlZWQAAAAD7gAAACAAAAAAAAAAAAAAABBjcmFzaF9tdWx0aXBsaWVyAAAABgAAAAAAAAAC",
+        "AAAABQAAAHlFbWl0dGVkIHdoZW4gYSByb3VuZCBzdG9wcyB0YWtpbmcgYmV0cyBhbmQgY29tbWl0dGVkIGNsaWVudCBzZWVkcyBjYW4gYmUKcmV2ZWFsZWQsIHRvcGljczogYFsiYmV0dGluZ19jbG9zZWQiLCByb3VuZF9pZF1gAAAAAAAAAAAAAA1CZXR0aW5nQ2xvc2VkAAAAAAAAAQAAAA5iZXR0aW5nX2Nsb3NlZAAAAAAAAQAAAAAAAAAIcm91bmRfaWQAAAAGAAAAAQAAAAI=",
+        "AAAABQAAAEhFbWl0dGVkIHdoZW4gYSBiZXQgaXMgcGxhY2VkLCB0b3BpY3M6IGBbImJldF9wbGFjZWQiLCByb3VuZF9pZCwgcGxheWVyXWAAAAAAAAAACUJldFBsYWNlZAAAAAAAAAEAAAAKYmV0X3BsYWNlZAAAAAAABQAAAAAAAAAIcm91bmRfaWQAAAAGAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAAAAAAAAGYmV0X2lkAAAAAAAGAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAAAAAANYXV0b19jYXNoX291dAAAAAAAA+gAAAAGAAAAAAAAAAI=",
+        "AAAABQAAAHVFbWl0dGVkIHdoZW4gYSBiZXQgaXMgY2FzaGVkIG91dCwgbWFudWFsbHkgb3IgYnkgaXRzIGF1dG8gY2FzaC1vdXQgdGFyZ2V0LAp0b3BpY3M6IGBbImNhc2hlZF9vdXQiLCByb3VuZF9pZCwgcGxheWVyXWAAAAAAAAAAAAAACUNhc2hlZE91dAAAAAAAAAEAAAAKY2FzaGVkX291dAAAAAAABQAAAAAAAAAIcm91bmRfaWQAAAAGAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAAAAAAAAGYmV0X2lkAAAAAAAGAAAAAAAAAAAAAAAKbXVsdGlwbGllcgAAAAAABgAAAAAAAAAAAAAABnBheW91dAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAH5FbWl0dGVkIHdoZW4gcGFydCBvZiBhIGJldCdzIHN0YWtlIGlzIGNhc2hlZCBvdXQgYW5kIHRoZSByZXN0IGtlZXBzIHJpZGluZywKdG9waWNzOiBgWyJwYXJ0aWFsX2Nhc2hlZF9vdXQiLCByb3VuZF9pZCwgcGxheWVyXWAAAAAAAAAAAAAQUGFydGlhbENhc2hlZE91dAAAAAEAAAAScGFydGlhbF9jYXNoZWRfb3V0AAAAAAAGAAAAAAAAAAhyb3VuZF9pZAAAAAYAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAAAAAAAAZiZXRfaWQAAAAAAAYAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAAAAAAptdWx0aXBsaWVyAAAAAAAGAAAAAAAAAAAAAAAGcGF5b3V0AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAGRFbWl0dGVkIHdoZW4gYSBwbGF5ZXIgcmV2ZWFscyBhIGNvbW1pdHRlZCBjbGllbnQgc2VlZCwKdG9waWNzOiBgWyJzZWVkX3JldmVhbGVkIiwgcm91bmRfaWQsIHBsYXllcl1gAAAAAAAAAAxTZWVkUmV2ZWFsZWQAAAABAAAADXNlZWRfcmV2ZWFsZWQAAAAAAAAEAAAAAAAAAAhyb3VuZF9pZAAAAAYAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAAAAAAAAZiZXRfaWQAAAAAAAYAAAAAAAAAAAAAAAtjbGllbnRfc2VlZAAAAAPuAAAAIAAAAAAAAAAC",
+        "AAAABQAAAIVFbWl0dGVkIHdoZW4gYSBiZXQgaXMgdm9pZGVkIGF0IHJvdW5kIHN0YXJ0IGJlY2F1c2UgaXRzIGNvbW1pdHRlZCBzZWVkCndhc24ndCByZXZlYWxlZCwgdG9waWNzOiBgWyJiZXRfZm9yZmVpdGVkIiwgcm91bmRfaWQsIHBsYXllcl1gAAAAAAAAAAAAAAxCZXRGb3JmZWl0ZWQAAAABAAAADWJldF9mb3JmZWl0ZWQAAAAAAAAFAAAAAAAAAAhyb3VuZF9pZAAAAAYAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAAAAAAAAZiZXRfaWQAAAAAAAYAAAAAAAAAAAAAAAZyZWZ1bmQAAAAAAAsAAAAAAAAAAAAAAAdwZW5hbHR5AAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAEpFbWl0dGVkIHdoZW4gYSByb3VuZCBpcyBmaW5hbGl6ZWQsIHRvcGljczogYFsicm91bmRfZmluYWxpemVkIiwgcm91bmRfaWRdYAAAAAAAAAAAAA5Sb3VuZEZpbmFsaXplZAAAAAAAAQAAAA9yb3VuZF9maW5hbGl6ZWQAAAAABgAAAAAAAAAIcm91bmRfaWQAAAAGAAAAAQAAAAAAAAAQY3Jhc2hfbXVsdGlwbGllcgAAAAYAAAAAAAAAAAAAAAx0b3RhbF9wYXlvdXQAAAALAAAAAAAAAAAAAAAMd2lubmVyX2NvdW50AAAABAAAAAAAAAAAAAAAC2xvc2VyX2NvdW50AAAAAAQAAAAAAAAAAAAAAAxob3VzZV9wcm9maXQAAAALAAAAAAAAAAI=",
+        "AAAABQAAAHFFbWl0dGVkIHdoZW4gYSByb3VuZCBpcyBjYW5jZWxsZWQsIGJ5IHRoZSBhZG1pbiBvciBieSBhIHRpbWVkIG91dCByZWZ1bmQsCnRvcGljczogYFsicm91bmRfY2FuY2VsbGVkIiwgcm91bmRfaWRdYAAAAAAAAAAAAAAOUm91bmRDYW5jZWxsZWQAAAAAAAEAAAAPcm91bmRfY2FuY2VsbGVkAAAAAAEAAAAAAAAACHJvdW5kX2lkAAAABgAAAAEAAAAC",
+        "AAAABQAAAE5FbWl0dGVkIHdoZW4gYSBiZXQgaXMgcmVmdW5kZWQsIHRvcGljczogYFsicmVmdW5kX2NsYWltZWQiLCByb3VuZF9pZCwgcGxheWVyXWAAAAAAAAAAAAANUmVmdW5kQ2xhaW1lZAAAAAAAAAEAAAAOcmVmdW5kX2NsYWltZWQAAAAAAAQAAAAAAAAACHJvdW5kX2lkAAAABgAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAAAAAABmJldF9pZAAAAAAABgAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAFNFbWl0dGVkIHdoZW4gYSBwcm92aWRlciBhZGRzIGxpcXVpZGl0eSwgdG9waWNzOiBgWyJsaXF1aWRpdHlfZGVwb3NpdGVkIiwgcHJvdmlkZXJdYAAAAAAAAAAAEkxpcXVpZGl0eURlcG9zaXRlZAAAAAAAAQAAABNsaXF1aWRpdHlfZGVwb3NpdGVkAAAAAAMAAAAAAAAACHByb3ZpZGVyAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAAAAAABnNoYXJlcwAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAGNFbWl0dGVkIHdoZW4gYSB3aXRoZHJhd2FsIHdhaXRzIGZvciBvcGVuIHJvdW5kcyB0byBjbG9zZSwKdG9waWNzOiBgWyJ3aXRoZHJhd2FsX3F1ZXVlZCIsIHByb3ZpZGVyXWAAAAAAAAAAABBXaXRoZHJhd2FsUXVldWVkAAAAAQAAABF3aXRoZHJhd2FsX3F1ZXVlZAAAAAAAAAIAAAAAAAAACHByb3ZpZGVyAAAAEwAAAAEAAAAAAAAABnNoYXJlcwAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAHpFbWl0dGVkIHdoZW4gYSBwcm92aWRlcidzIHNoYXJlcyBhcmUgYnVybmVkIGF0IHRoZSBiYW5rcm9sbCdzIG5ldCBhc3NldAp2YWx1ZSwgdG9waWNzOiBgWyJsaXF1aWRpdHlfd2l0aGRyYXduIiwgcHJvdmlkZXJdYAAAAAAAAAAAABJMaXF1aWRpdHlXaXRoZHJhd24AAAAAAAEAAAATbGlxdWlkaXR5X3dpdGhkcmF3bgAAAAADAAAAAAAAAAhwcm92aWRlcgAAABMAAAABAAAAAAAAAAZzaGFyZXMAAAAAAAsAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAF9FbWl0dGVkIHdoZW4gYSBwcm92aWRlciBjbGFpbXMgYSBzZXR0bGVkIHdpdGhkcmF3YWwsCnRvcGljczogYFsid2l0aGRyYXdhbF9jbGFpbWVkIiwgcHJvdmlkZXJdYAAAAAAAAAAAEVdpdGhkcmF3YWxDbGFpbWVkAAAAAAAAAQAAABJ3aXRoZHJhd2FsX2NsYWltZWQAAAAAAAIAAAAAAAAACHByb3ZpZGVyAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAFtFbWl0dGVkIHdoZW4gYSBwbGF5ZXIgY2xhaW1zIHBheW91dHMgY3JlZGl0ZWQgdG8gdGhlbSwKdG9waWNzOiBgWyJwYXlvdXRfY2xhaW1lZCIsIHBsYXllcl1gAAAAAAAAAAANUGF5b3V0Q2xhaW1lZAAAAAAAAAEAAAAOcGF5b3V0X2NsYWltZWQAAAAAAAIAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAGNFbWl0dGVkIHdoZW4gdGhlIGFkbWluIHByb3Bvc2VzIGEgbmV3IGFkbWluLAp0b3BpY3M6IGBbImFkbWluX3Byb3Bvc2VkIiwgY3VycmVudF9hZG1pbiwgbmV3X2FkbWluXWAAAAAAAAAAAA1BZG1pblByb3Bvc2VkAAAAAAAAAQAAAA5hZG1pbl9wcm9wb3NlZAAAAAAAAgAAAAAAAAANY3VycmVudF9hZG1pbgAAAAAAABMAAAABAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAQAAAAI=",
+        "AAAABQAAAGNFbWl0dGVkIHdoZW4gdGhlIHByb3Bvc2VkIGFkbWluIGFjY2VwdHMsCnRvcGljczogYFsiYWRtaW5fdHJhbnNmZXJyZWQiLCBwcmV2aW91c19hZG1pbiwgbmV3X2FkbWluXWAAAAAAAAAAABBBZG1pblRyYW5zZmVycmVkAAAAAQAAABFhZG1pbl90cmFuc2ZlcnJlZAAAAAAAAAIAAAAAAAAADnByZXZpb3VzX2FkbWluAAAAAAATAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAEAAAAC",
+        "AAAABQAAAEZFbWl0dGVkIHdoZW4gdGhlIGFkbWluIGFzc2lnbnMgYSByb2xlLCB0b3BpY3M6IGBbInJvbGVfc2V0IiwgYWNjb3VudF1gAAAAAAAAAAAAB1JvbGVTZXQAAAAAAQAAAAhyb2xlX3NldAAAAAIAAAAAAAAAB2FjY291bnQAAAAAEwAAAAEAAAAAAAAABHJvbGUAAAfQAAAABFJvbGUAAAAAAAAAAg==",
+        "AAAABQAAAElFbWl0dGVkIHdoZW4gdGhlIHBhdXNlciBwYXVzZXMgdGhlIGNvbnRyYWN0LCB0b3BpY3M6IGBbInBhdXNlZCIsIHBhdXNlcl1gAAAAAAAAAAAAAAZQYXVzZWQAAAAAAAEAAAAGcGF1c2VkAAAAAAABAAAAAAAAAAZwYXVzZXIAAAAAABMAAAABAAAAAg==",
+        "AAAABQAAAE1FbWl0dGVkIHdoZW4gdGhlIHBhdXNlciB1bnBhdXNlcyB0aGUgY29udHJhY3QsIHRvcGljczogYFsidW5wYXVzZWQiLCBwYXVzZXJdYAAAAAAAAAAAAAAIVW5wYXVzZWQAAAABAAAACHVucGF1c2VkAAAAAQAAAAAAAAAGcGF1c2VyAAAAAAATAAAAAQAAAAI=",
+        "AAAABQAAAFJFbWl0dGVkIHdoZW4gdGhlIGFkbWluIHJlcGxhY2VzIHRoZSBjb250cmFjdCBjb2RlLAp0b3BpY3M6IGBbImNvbnRyYWN0X3VwZ3JhZGVkIl1gAAAAAAAAAAAAEENvbnRyYWN0VXBncmFkZWQAAAABAAAAEWNvbnRyYWN0X3VwZ3JhZGVkAAAAAAAAAQAAAAAAAAANbmV3X3dhc21faGFzaAAAAAAAA+4AAAAgAAAAAAAAAAI=",
+        "AAAABQAAAF1FbWl0dGVkIHdoZW4gYG1pZ3JhdGVgIGJyaW5ncyBzdG9yYWdlIHRvIGEgbmV3IHNjaGVtYSB2ZXJzaW9uLAp0b3BpY3M6IGBbInN0b3JhZ2VfbWlncmF0ZWQiXWAAAAAAAAAAAAAAD1N0b3JhZ2VNaWdyYXRlZAAAAAABAAAAEHN0b3JhZ2VfbWlncmF0ZWQAAAACAAAAAAAAAAxmcm9tX3ZlcnNpb24AAAAEAAAAAAAAAAAAAAAKdG9fdmVyc2lvbgAAAAAABAAAAAAAAAAC",
+        "AAAABQAAAFdFbWl0dGVkIHdoZW4gdGhlIHRyZWFzdXJlciB1cGRhdGVzIHRoZSBnYW1lIGNvbmZpZ3VyYXRpb24sCnRvcGljczogYFsiY29uZmlnX3VwZGF0ZWQiXWAAAAAAAAAAAA1Db25maWdVcGRhdGVkAAAAAAAAAQAAAA5jb25maWdfdXBkYXRlZAAAAAAAAQAAAAAAAAAGY29uZmlnAAAAAAfQAAAABkNvbmZpZwAAAAAAAAAAAAI=",
+        "AAAAAgAAANlLZXlzIGZvciBwZXJzaXN0ZW50IHN0b3JhZ2UgKGFuZCBrZXllZCBpbnN0YW5jZSBlbnRyaWVzIHN1Y2ggYXMgcm9sZXMpCgpSb3VuZHMgYW5kIGJldHMgdXNlZCB0byBiZSBzdG9yZWQgdW5kZXIgdGhlaXIgYmFyZSBgdTY0YCBpZCwgd2hpY2ggcHV0CmJvdGggaW4gdGhlIHNhbWUga2V5c3BhY2UuIFRhZ2dpbmcgZWFjaCBpZCB3aXRoIGl0cyBraW5kIGtlZXBzIHRoZW0gYXBhcnQuAAAAAAAAAAAAAAdEYXRhS2V5AAAAABEAAAABAAAAAAAAAAVSb3VuZAAAAAAAAAEAAAAGAAAAAQAAAAAAAAADQmV0AAAAAAEAAAAGAAAAAQAAAAAAAAAJUm91bmRCZXRzAAAAAAAAAQAAAAYAAAABAAAAAAAAAAhMcFNoYXJlcwAAAAEAAAATAAAAAQAAAAAAAAARUGVuZGluZ1dpdGhkcmF3YWwAAAAAAAABAAAAEwAAAAEAAAAAAAAAE0NsYWltYWJsZVdpdGhkcmF3YWwAAAAAAQAAABMAAAABAAAAAAAAAARSb2xlAAAAAQAAB9AAAAAEUm9sZQAAAAEAAAAAAAAADlBsYXllckJldENvdW50AAAAAAABAAAAEwAAAAEAAAAAAAAAClBsYXllckJldHMAAAAAAAIAAAATAAAABAAAAAEAAAAAAAAAC1BsYXllclN0YXRzAAAAAAEAAAATAAAAAQAAAAAAAAAOU2VlZENvbW1pdG1lbnQAAAAAAAEAAAAGAAAAAQAAAAAAAAANUmV2ZWFsZWRTZWVkcwAAAAAAAAEAAAAGAAAAAQAAAAAAAAAMUm91bmRFbnRyb3B5AAAAAQAAAAYAAAABAAAAAAAAAA9QbGF5ZXJSb3VuZEJldHMAAAAAAgAAABMAAAAGAAAAAQAAAAAAAAAPV2l0aGRyYXdhbEVwb2NoAAAAAAEAAAAEAAAAAQAAAAAAAAAMVW5wYWlkUGF5b3V0AAAAAQAAABMAAAABAAAAAAAAAA5Sb3VuZENoYWluTGluawAAAAAAAQAAAAY=",
+        "AAAAAgAAAAAAAAAAAAAAC1JvdW5kU3RhdHVzAAAAAAUAAAAAAAAAAAAAAAdXYWl0aW5nAAAAAAAAAAAAAAAACVJldmVhbGluZwAAAAAAAAAAAAAAAAAACkluUHJvZ3Jlc3MAAAAAAAAAAAAAAAAABUVuZGVkAAAAAAAAAAAAAAAAAAAJQ2FuY2VsbGVkAAAA",
+        "AAAAAgAAAZZSb2xlcyB0aGUgYWRtaW4gY2FuIGFzc2lnbiwgZWFjaCBoZWxkIGJ5IGEgc2luZ2xlIGFkZHJlc3MKCktlcHQgaW4taG91c2UgcmF0aGVyIHRoYW4gb24gdGhlIHdvcmtzcGFjZSdzIGBzdGVsbGFyLWFjY2Vzc2A6IGl0cwpgQWNjZXNzQ29udHJvbGAgZ3JhbnRzIGBTeW1ib2xgIHJvbGVzIHRvIGFueSBudW1iZXIgb2YgYWNjb3VudHMsIG1hbmFnZWQKYnkgcGVyLXJvbGUgYWRtaW5zLCB3aGlsZSBlYWNoIHJvbGUgaGVyZSBpcyBvbmUgYWRkcmVzcyB0aGF0IGBnZXRfcm9sZWAKcmV0dXJucyBhbmQgYHNldF9yb2xlYCByZXBsYWNlcy4gTW92aW5nIG92ZXIgd291bGQgY2hhbmdlIGJvdGggZW50cnkKcG9pbnRzIGFuZCBuZWVkIGEgbWlncmF0aW9uIG9mIHRoZSBzdG9yZWQgYERhdGFLZXk6OlJvbGVgIGVudHJpZXMuAAAAAAAAAAAABFJvbGUAAAADAAAAAAAAAAAAAAAIT3BlcmF0b3IAAAAAAAAAAAAAAAlUcmVhc3VyZXIAAAAAAAAAAAAAAAAAAAZQYXVzZXIAAA==",
+        "AAAAAQAAACtHYW1lIHBhcmFtZXRlcnMsIHVwZGF0YWJsZSBieSB0aGUgdHJlYXN1cmVyAAAAAAAAAAAGQ29uZmlnAAAAAAANAAAAAAAAAA5ob3VzZV9lZGdlX2JwcwAAAAAABAAAAAAAAAATaW5fcHJvZ3Jlc3NfdGltZW91dAAAAAAGAAAAAAAAAAdtYXhfYmV0AAAAAAsAAAAAAAAAE21heF9iZXRzX3Blcl9wbGF5ZXIAAAAABAAAAAAAAAASbWF4X2JldHNfcGVyX3JvdW5kAAAAAAAEAAAAAAAAABBtYXhfY2xpZW50X3NlZWRzAAAABAAAAAAAAAAQbWF4X2V4cG9zdXJlX2JwcwAAAAQAAAAAAAAADm1heF9tdWx0aXBsaWVyAAAAAAAGAAAAAAAAAAdtaW5fYmV0AAAAAAsAAAAAAAAAEW1pbl9yZXZlYWxfd2luZG93AAAAAAAABgAAAAAAAAASbWl4X2xlZGdlcl9lbnRyb3B5AAAAAAABAAAAAAAAABZ1bnJldmVhbGVkX3BlbmFsdHlfYnBzAAAAAAAEAAAAAAAAAA93YWl0aW5nX3RpbWVvdXQAAAAABg==",
+        "AAAAAQAAAAAAAAAAAAAABVJvdW5kAAAAAAAAFgAAAAAAAAAJYmV0X2NvdW50AAAAAAAABAAAAAAAAAAMY2xpZW50X3NlZWRzAAAD6gAAA+4AAAAgAAAAAAAAAA5jb21taXR0ZWRfYmV0cwAAAAAABAAAAAAAAAAQY3Jhc2hfbXVsdGlwbGllcgAAAAYAAAAAAAAACmNyZWF0ZWRfYXQAAAAAAAYAAAAAAAAACGVuZGVkX2F0AAAABgAAAAAAAAAOaG91c2VfZWRnZV9icHMAAAAAAAQAAAAAAAAADGhvdXNlX3Byb2ZpdAAAAAsAAAAAAAAAAmlkAAAAAAAGAAAAAAAAABNpbl9wcm9ncmVzc190aW1lb3V0AAAAAAYAAAAAAAAAC2xvc2VyX2NvdW50AAAAAAQAAAAAAAAADm1heF9tdWx0aXBsaWVyAAAAAAAGAAAAAAAAABJtaXhfbGVkZ2VyX2VudHJvcHkAAAAAAAEAAAAAAAAADnJldmVhbF9lbmRzX2F0AAAAAAAGAAAAAAAAAAtzZXJ2ZXJfc2VlZAAAAAPoAAAD7gAAACAAAAAAAAAAEHNlcnZlcl9zZWVkX2hhc2gAAAPuAAAAIAAAAAAAAAAKc3RhcnRlZF9hdAAAAAAABgAAAAAAAAAGc3RhdHVzAAAAAAfQAAAAC1JvdW5kU3RhdHVzAAAAAAAAAAAQdG90YWxfYmV0X2Ftb3VudAAAAAsAAAAAAAAADHRvdGFsX3BheW91dAAAAAsAAAAAAAAAD3dhaXRpbmdfdGltZW91dAAAAAAGAAAAAAAAAAx3aW5uZXJfY291bnQAAAAE",
+        "AAAAAQAAARxDb21taXRtZW50IHRvIGEgU0hBLTI1NiBjaGFpbiBvZiBzZXJ2ZXIgc2VlZHMKClRoZSBvcGVyYXRvciBkcmF3cyBhIHJhbmRvbSBgc2VlZF9uYCwgY29tcHV0ZXMgYHNlZWRfe2ktMX0gPSBzaGEyNTYoc2VlZF9pKWAKZG93biB0byBgc2VlZF8xYCBhbmQgY29tbWl0cyBgdGVybWluYWxfaGFzaCA9IHNoYTI1NihzZWVkXzEpYC4gUm91bmRzIHRoZW4KcmV2ZWFsIGBzZWVkXzFgLCBgc2VlZF8yYCwgLi4uIGluIG9yZGVyLCBlYWNoIGhhc2hpbmcgdG8gdGhlIGxpbmsgcmV2ZWFsZWQKYmVmb3JlIGl0LgAAAAAAAAAJU2VlZENoYWluAAAAAAAABAAAAAAAAAAEaGVhZAAAA+4AAAAgAAAAAAAAAAZsZW5ndGgAAAAAAAQAAAAAAAAACHJldmVhbGVkAAAABAAAAAAAAAANdGVybWluYWxfaGFzaAAAAAAAA+4AAAAg",
+        "AAAAAQAAAGBEYXRhIGRyYXduIHdoZW4gYSByb3VuZCBzdGFydHMsIHdoaWNoIHRoZSBvcGVyYXRvciBjYW4ndCBrbm93IHdoZW4KY29tbWl0dGluZyB0byB0aGUgc2VydmVyIHNlZWQAAAAAAAAADFJvdW5kRW50cm9weQAAAAQAAAAAAAAAB2VudHJvcHkAAAAD7gAAACAAAAAAAAAAD2xlZGdlcl9zZXF1ZW5jZQAAAAAEAAAAAAAAABBsZWRnZXJfdGltZXN0YW1wAAAABgAAAAAAAAAJcHJuZ19zZWVkAAAAAAAD7gAAACA=",
+        "AAAAAQAAAEhQb3NpdGlvbiBvZiBhIHJvdW5kJ3Mgc2VydmVyIHNlZWQgaW4gdGhlIHNlZWQgY2hhaW4gaXQgd2FzIHJldmVhbGVkIGZyb20AAAAAAAAADVNlZWRDaGFpbkxpbmsAAAAAAAADAAAAAAAAAAZsZW5ndGgAAAAAAAQAAAAAAAAACHBvc2l0aW9uAAAABAAAAAAAAAANdGVybWluYWxfaGFzaAAAAAAAA+4AAAAg",
+        "AAAAAgAAADpTZWVkIGNoYWluIGEgcm91bmQncyBzZXJ2ZXIgc2VlZCB3YXMgcmV2ZWFsZWQgZnJvbSwgaWYgYW55AAAAAAAAAAAAC0NoYWluU291cmNlAAAAAAIAAAAAAAAAAAAAAAROb25lAAAAAQAAAAAAAAAETGluawAAAAEAAAfQAAAADVNlZWRDaGFpbkxpbmsAAAA=",
+        "AAAAAgAAADZFeHRyYSBkYXRhIG1peGVkIGludG8gYSByb3VuZCdzIGhhc2ggYmVzaWRlcyB0aGUgc2VlZHMAAAAAAAAAAAANRW50cm9weVNvdXJjZQAAAAAAAAIAAAAAAAAAAAAAAAROb25lAAAAAQAAAAAAAAAGTGVkZ2VyAAAAAAABAAAH0AAAAAxSb3VuZEVudHJvcHk=",
+        "AAAAAQAAAEdSZXN1bHQgb2YgcmVjb21wdXRpbmcgYSByb3VuZCdzIHByb3ZhYmx5IGZhaXIgb3V0Y29tZSBmcm9tIHN0b3JlZCBzZWVkcwAAAAAAAAAAEVJvdW5kVmVyaWZpY2F0aW9uAAAAAAAADQAAAAAAAAAMY2xpZW50X3NlZWRzAAAD6gAAA+4AAAAgAAAAAAAAABBjcmFzaF9tdWx0aXBsaWVyAAAABgAAAAAAAAAWY3Jhc2hfbXVsdGlwbGllcl92YWxpZAAAAAAAAQAAAAAAAAAHZW50cm9weQAAAAfQAAAADUVudHJvcHlTb3VyY2UAAAAAAAAAAAAAB2Zvcm11bGEAAAAAEAAAAAAAAAAZcmVjb3JkZWRfY3Jhc2hfbXVsdGlwbGllcgAAAAAAAAYAAAAAAAAACnJvdW5kX2hhc2gAAAAAA+4AAAAgAAAAAAAAAAhyb3VuZF9pZAAAAAYAAAAAAAAACnNlZWRfY2hhaW4AAAAAB9AAAAALQ2hhaW5Tb3VyY2UAAAAAAAAAAA9zZWVkX2hhc2hfdmFsaWQAAAAAAQAAAAAAAAALc2VydmVyX3NlZWQAAAAD7gAAACAAAAAAAAAAEHNlcnZlcl9zZWVkX2hhc2gAAAPuAAAAIAAAAAAAAAAIdmVyaWZpZWQAAAAB",
+        "AAAAAgAAAAAAAAAAAAAACUJldFN0YXR1cwAAAAAAAAUAAAAAAAAAAAAAAAZBY3RpdmUAAAAAAAAAAAAAAAAACUNhc2hlZE91dAAAAAAAAAAAAAAAAAAABExvc3QAAAAAAAAAAAAAAAhSZWZ1bmRlZAAAAAAAAAAAAAAACUZvcmZlaXRlZAAAAA==",
+        "AAAAAQAAAAAAAAAAAAAAA0JldAAAAAAKAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAADWF1dG9fY2FzaF9vdXQAAAAAAAPoAAAABgAAAAAAAAATY2FzaF9vdXRfbXVsdGlwbGllcgAAAAAGAAAAAAAAAAJpZAAAAAAABgAAAAAAAAARcGFydGlhbF9jYXNoX291dHMAAAAAAAPqAAAH0AAAAA5QYXJ0aWFsQ2FzaE91dAAAAAAAAAAAAAZwYXlvdXQAAAAAAAsAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAIcm91bmRfaWQAAAAGAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAJQmV0U3RhdHVzAAAAAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAG",
+        "AAAAAQAAAAAAAAAAAAAADlBhcnRpYWxDYXNoT3V0AAAAAAAEAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAACm11bHRpcGxpZXIAAAAAAAYAAAAAAAAABnBheW91dAAAAAAACwAAAAAAAAAJdGltZXN0YW1wAAAAAAAABg==",
+        "AAAAAQAAAAAAAAAAAAAAC1BsYXllclN0YXRzAAAAAAcAAAAAAAAAEmJpZ2dlc3RfbXVsdGlwbGllcgAAAAAABgAAAAAAAAAOYmlnZ2VzdF9wYXlvdXQAAAAAAAsAAAAAAAAAC2xhc3RfcGxheWVkAAAAAAYAAAAAAAAAB25ldF9wbmwAAAAACwAAAAAAAAANcm91bmRzX3BsYXllZAAAAAAAAAQAAAAAAAAADXRvdGFsX3dhZ2VyZWQAAAAAAAALAAAAAAAAAAl0b3RhbF93b24AAAAAAAAL",
+        "AAAAAQAAAAAAAAAAAAAABFBvb2wAAAAFAAAAAAAAAAhiYW5rcm9sbAAAAAsAAAAAAAAACnRvdGFsX2JldHMAAAAAAAsAAAAAAAAAFHRvdGFsX2hvdXNlX2Vhcm5pbmdzAAAACwAAAAAAAAANdG90YWxfcGF5b3V0cwAAAAAAAAsAAAAAAAAADHRvdGFsX3NoYXJlcwAAAAs=",
+        "AAAAAQAAACpMUCBzaGFyZXMgYSBwcm92aWRlciBxdWV1ZWQgZm9yIHdpdGhkcmF3YWwAAAAAAAAAAAAQUXVldWVkV2l0aGRyYXdhbAAAAAIAAAAAAAAABWVwb2NoAAAAAAAABAAAAAAAAAAGc2hhcmVzAAAAAAAL",
+        "AAAAAQAAALhTZXR0bGVkIHNoYXJlIHByaWNlIG9mIGEgd2l0aGRyYXdhbCBlcG9jaAoKQWxsIHNoYXJlcyBxdWV1ZWQgaW4gdGhlIGVwb2NoIGFyZSBidXJuZWQgdG9nZXRoZXIgd2hlbiB0aGUgbGFzdCBvcGVuCnJvdW5kIGNsb3Nlcy4gRWFjaCBwcm92aWRlciB0aGVuIGdldHMgYHNoYXJlcyAqIGFtb3VudCAvIHRvdGFsIHNoYXJlc2AuAAAAAAAAAAxTZXR0bGVkRXBvY2gAAAACAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAABnNoYXJlcwAAAAAACw==",
+        "AAAAAQAAAG5Sb3VuZCBsYXlvdXQgb2Ygc2NoZW1hIHZlcnNpb24gMSwgYmVmb3JlIHNlZWRzLCBjb25maWcgc25hcHNob3RzIGFuZApzZXR0bGVtZW50IHN0YXRzIHdlcmUgc3RvcmVkIG9uIHRoZSByb3VuZAAAAAAAAAAAAAdSb3VuZFYxAAAAAAsAAAAAAAAACWJldF9jb3VudAAAAAAAAAQAAAAAAAAADGNsaWVudF9zZWVkcwAAA+oAAAPuAAAAIAAAAAAAAAAQY3Jhc2hfbXVsdGlwbGllcgAAAAYAAAAAAAAACmNyZWF0ZWRfYXQAAAAAAAYAAAAAAAAACGVuZGVkX2F0AAAABgAAAAAAAAACaWQAAAAAAAYAAAAAAAAAEHNlcnZlcl9zZWVkX2hhc2gAAAPuAAAAIAAAAAAAAAAKc3RhcnRlZF9hdAAAAAAABgAAAAAAAAAGc3RhdHVzAAAAAAfQAAAAC1JvdW5kU3RhdHVzAAAAAAAAAAAQdG90YWxfYmV0X2Ftb3VudAAAAAsAAAAAAAAADHRvdGFsX3BheW91dAAAAAs=",
+        "AAAAAQAAAGBSb3VuZCBsYXlvdXQgb2Ygc2NoZW1hIHZlcnNpb25zIDIgdG8gNywgYmVmb3JlIHRoZSBsZWRnZXIgZW50cm9weSBzd2l0Y2gKd2FzIHN0b3JlZCBvbiB0aGUgcm91bmQAAAAAAAAAB1JvdW5kVjIAAAAAEQAAAAAAAAAJYmV0X2NvdW50AAAAAAAABAAAAAAAAAAMY2xpZW50X3NlZWRzAAAD6gAAA+4AAAAgAAAAAAAAABBjcmFzaF9tdWx0aXBsaWVyAAAABgAAAAAAAAAKY3JlYXRlZF9hdAAAAAAABgAAAAAAAAAIZW5kZWRfYXQAAAAGAAAAAAAAAA5ob3VzZV9lZGdlX2JwcwAAAAAABAAAAAAAAAAMaG91c2VfcHJvZml0AAAACwAAAAAAAAACaWQAAAAAAAYAAAAAAAAAC2xvc2VyX2NvdW50AAAAAAQAAAAAAAAADm1heF9tdWx0aXBsaWVyAAAAAAAGAAAAAAAAAAtzZXJ2ZXJfc2VlZAAAAAPoAAAD7gAAACAAAAAAAAAAEHNlcnZlcl9zZWVkX2hhc2gAAAPuAAAAIAAAAAAAAAAKc3RhcnRlZF9hdAAAAAAABgAAAAAAAAAGc3RhdHVzAAAAAAfQAAAAC1JvdW5kU3RhdHVzAAAAAAAAAAAQdG90YWxfYmV0X2Ftb3VudAAAAAsAAAAAAAAADHRvdGFsX3BheW91dAAAAAsAAAAAAAAADHdpbm5lcl9jb3VudAAAAAQ=",
+        "AAAAAQAAAFlSb3VuZCBsYXlvdXQgb2Ygc2NoZW1hIHZlcnNpb25zIDggYW5kIDksIGJlZm9yZSB0aGUgcmV2ZWFsIHBoYXNlIHdhcwp0cmFja2VkIG9uIHRoZSByb3VuZAAAAAAAAAAAAAAHUm91bmRWMwAAAAASAAAAAAAAAAliZXRfY291bnQAAAAAAAAEAAAAAAAAAAxjbGllbnRfc2VlZHMAAAPqAAAD7gAAACAAAAAAAAAAEGNyYXNoX211bHRpcGxpZXIAAAAGAAAAAAAAAApjcmVhdGVkX2F0AAAAAAAGAAAAAAAAAAhlbmRlZF9hdAAAAAYAAAAAAAAADmhvdXNlX2VkZ2VfYnBzAAAAAAAEAAAAAAAAAAxob3VzZV9wcm9maXQAAAALAAAAAAAAAAJpZAAAAAAABgAAAAAAAAALbG9zZXJfY291bnQAAAAABAAAAAAAAAAObWF4X211bHRpcGxpZXIAAAAAAAYAAAAAAAAAEm1peF9sZWRnZXJfZW50cm9weQAAAAAAAQAAAAAAAAALc2VydmVyX3NlZWQAAAAD6AAAA+4AAAAgAAAAAAAAABBzZXJ2ZXJfc2VlZF9oYXNoAAAD7gAAACAAAAAAAAAACnN0YXJ0ZWRfYXQAAAAAAAYAAAAAAAAABnN0YXR1cwAAAAAH0AAAAAtSb3VuZFN0YXR1cwAAAAAAAAAAEHRvdGFsX2JldF9hbW91bnQAAAALAAAAAAAAAAx0b3RhbF9wYXlvdXQAAAALAAAAAAAAAAx3aW5uZXJfY291bnQAAAAE",
+        "AAAAAQAAAFZSb3VuZCBsYXlvdXQgb2Ygc2NoZW1hIHZlcnNpb24gMTAsIGJlZm9yZSB0aGUgcmVmdW5kIHRpbWVvdXRzIHdlcmUKc3RvcmVkIG9uIHRoZSByb3VuZAAAAAAAAAAAAAdSb3VuZFY0AAAAABQAAAAAAAAACWJldF9jb3VudAAAAAAAAAQAAAAAAAAADGNsaWVudF9zZWVkcwAAA+oAAAPuAAAAIAAAAAAAAAAOY29tbWl0dGVkX2JldHMAAAAAAAQAAAAAAAAAEGNyYXNoX211bHRpcGxpZXIAAAAGAAAAAAAAAApjcmVhdGVkX2F0AAAAAAAGAAAAAAAAAAhlbmRlZF9hdAAAAAYAAAAAAAAADmhvdXNlX2VkZ2VfYnBzAAAAAAAEAAAAAAAAAAxob3VzZV9wcm9maXQAAAALAAAAAAAAAAJpZAAAAAAABgAAAAAAAAALbG9zZXJfY291bnQAAAAABAAAAAAAAAAObWF4X211bHRpcGxpZXIAAAAAAAYAAAAAAAAAEm1peF9sZWRnZXJfZW50cm9weQAAAAAAAQAAAAAAAAAOcmV2ZWFsX2VuZHNfYXQAAAAAAAYAAAAAAAAAC3NlcnZlcl9zZWVkAAAAA+gAAAPuAAAAIAAAAAAAAAAQc2VydmVyX3NlZWRfaGFzaAAAA+4AAAAgAAAAAAAAAApzdGFydGVkX2F0AAAAAAAGAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAALUm91bmRTdGF0dXMAAAAAAAAAABB0b3RhbF9iZXRfYW1vdW50AAAACwAAAAAAAAAMdG90YWxfcGF5b3V0AAAACwAAAAAAAAAMd2lubmVyX2NvdW50AAAABA==",
+        "AAAAAQAAADRCZXQgbGF5b3V0IG9mIHNjaGVtYSB2ZXJzaW9uIDEsIGJlZm9yZSBhdXRvIGNhc2gtb3V0AAAAAAAAAAVCZXRWMQAAAAAAAAgAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAATY2FzaF9vdXRfbXVsdGlwbGllcgAAAAAGAAAAAAAAAAJpZAAAAAAABgAAAAAAAAAGcGF5b3V0AAAAAAALAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACHJvdW5kX2lkAAAABgAAAAAAAAAGc3RhdHVzAAAAAAfQAAAACUJldFN0YXR1cwAAAAAAAAAAAAAJdGltZXN0YW1wAAAAAAAABg==",
+        "AAAAAQAAAD5CZXQgbGF5b3V0IG9mIHNjaGVtYSB2ZXJzaW9ucyAyIHRvIDUsIGJlZm9yZSBwYXJ0aWFsIGNhc2gtb3V0cwAAAAAAAAAAAAVCZXRWMgAAAAAAAAkAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAANYXV0b19jYXNoX291dAAAAAAAA+gAAAAGAAAAAAAAABNjYXNoX291dF9tdWx0aXBsaWVyAAAAAAYAAAAAAAAAAmlkAAAAAAAGAAAAAAAAAAZwYXlvdXQAAAAAAAsAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAIcm91bmRfaWQAAAAGAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAJQmV0U3RhdHVzAAAAAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAG",
+        "AAAAAQAAAEJQb29sIGxheW91dCBvZiBzY2hlbWEgdmVyc2lvbiAxLCBiZWZvcmUgdGhlIGJhbmtyb2xsIGFuZCBMUCBzaGFyZXMAAAAAAAAAAAAGUG9vbFYxAAAAAAADAAAAAAAAAAp0b3RhbF9iZXRzAAAAAAALAAAAAAAAABR0b3RhbF9ob3VzZV9lYXJuaW5ncwAAAAsAAAAAAAAADXRvdGFsX3BheW91dHMAAAAAAAAL",
+        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIGFkbWluIGFkZHJlc3MgYW5kIHRoZSB0b2tlbiB1c2VkIGZvciBiZXRzCih0aGUgU3RlbGxhciBBc3NldCBDb250cmFjdCBmb3IgWExNIGluIHByb2R1Y3Rpb24pCgpUaGUgYWRtaW4gc3RhcnRzIG91dCBob2xkaW5nIGV2ZXJ5IHJvbGUuAAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAA",
         "AAAAAAAAABlHZXQgY3VycmVudCBhZG1pbiBhZGRyZXNzAAAAAAAABWFkbWluAAAAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAFdDcmVhdGUgYSBuZXcgcm91bmQgKGFkbWluIG9ubHkpCgpTZWN1cml0eTogT25seSBhZG1pbiBjYW4gY3JlYXRlIHJvdW5kcyB0byBwcmV2ZW50IHNwYW0AAAAADGNyZWF0ZV9yb3VuZAAAAAIAAAAAAAAACHJvdW5kX2lkAAAABgAAAAAAAAAQc2VydmVyX3NlZWRfaGFzaAAAA+4AAAAgAAAAAQAAA+kAAAfQAAAABVJvdW5kAAAAAAAAAw==",
-        "AAAAAAAAAG9TdGFydCB0aGUgcm91bmQgd2l0aCBzZXJ2ZXIgc2VlZCAoYWRtaW4gb25seSkKClNlY3VyaXR5OiBTZXJ2ZXIgc2VlZCBtdXN0IG1hdGNoIGhhc2gsIG11bHRpcGxpZXIgcHJlLWRldGVybWluZWQAAAAAC3N0YXJ0X3JvdW5kAAAAAAMAAAAAAAAACHJvdW5kX2lkAAAABgAAAAAAAAALc2VydmVyX3NlZWQAAAAD7gAAACAAAAAAAAAAEGNyYXNoX211bHRpcGxpZXIAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAAKlQbGFjZSBhIGJldCBpbiB0aGUgY3VycmVudCByb3VuZAoKU2VjdXJpdHk6Ci0gQ2hlY2tzIHBsYXllciBiYWxhbmNlCi0gVmFsaWRhdGVzIGJldCBhbW91bnQgKG1pbi9tYXgpCi0gUHJldmVudHMgYmV0dGluZyBhZnRlciByb3VuZCBzdGFydGVkCi0gVXNlcyB0b2tlbiB0cmFuc2ZlciBmb3IgWExNAAAAAAAACXBsYWNlX2JldAAAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAIcm91bmRfaWQAAAAGAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAC2NsaWVudF9zZWVkAAAAA+4AAAAgAAAAAQAAA+kAAAAGAAAAAw==",
-        "AAAAAAAAAL1DYXNoIG91dCBhIGJldCBhdCBjdXJyZW50IG11bHRpcGxpZXIKClNlY3VyaXR5OgotIFZlcmlmaWVzIGJldCBvd25lcnNoaXAKLSBDaGVja3MgYmV0IGlzIGFjdGl2ZQotIFZhbGlkYXRlcyBtdWx0aXBsaWVyIGhhc24ndCBjcmFzaGVkCi0gQ2FsY3VsYXRlcyBwYXlvdXQgd2l0aCBob3VzZSBlZGdlCi0gUHJldmVudHMgcmUtZW50cnkAAAAAAAAIY2FzaF9vdXQAAAADAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABmJldF9pZAAAAAAABgAAAAAAAAASY3VycmVudF9tdWx0aXBsaWVyAAAAAAAGAAAAAQAAA+kAAAALAAAAAw==",
-        "AAAAAAAAAJxGaW5hbGl6ZSB0aGUgcm91bmQgKGFkbWluIG9ubHkpCgpTZWN1cml0eToKLSBPbmx5IGFkbWluIGNhbiBmaW5hbGl6ZQotIFZlcmlmaWVzIHJvdW5kIGlzIGluIHByb2dyZXNzCi0gTWFya3MgYWxsIHVuY2FzaGVkIGJldHMgYXMgbG9zdAotIFJlY29yZHMgZmluYWwgc3RhdHMAAAAOZmluYWxpemVfcm91bmQAAAAAAAEAAAAAAAAACHJvdW5kX2lkAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAALdQcm9wb3NlIGEgbmV3IGFkbWluIChhZG1pbiBvbmx5KQoKVGhlIHRyYW5zZmVyIG9ubHkgaGFwcGVucyBvbmNlIHRoZSBuZXcgYWRtaW4gY2FsbHMgYGFjY2VwdF9hZG1pbmAsCnNvIGEgdHlwbyBjYW4ndCBsb2NrIHRoZSBjb250cmFjdC4gUHJvcG9zaW5nIGFnYWluIHJlcGxhY2VzIHRoZQpwcmV2aW91cyBwcm9wb3NhbC4AAAAADXByb3Bvc2VfYWRtaW4AAAAAAAABAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAA==",
+        "AAAAAAAAADVBY2NlcHQgYSBwZW5kaW5nIGFkbWluIHRyYW5zZmVyIChwcm9wb3NlZCBhZG1pbiBvbmx5KQAAAAAAAAxhY2NlcHRfYWRtaW4AAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAADhHZXQgYWRtaW4gYWRkcmVzcyB3YWl0aW5nIHRvIGFjY2VwdCB0aGUgdHJhbnNmZXIsIGlmIGFueQAAAA1wZW5kaW5nX2FkbWluAAAAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAChBc3NpZ24gYSByb2xlIHRvIGFuIGFkZHJlc3MgKGFkbWluIG9ubHkpAAAACHNldF9yb2xlAAAAAgAAAAAAAAAEcm9sZQAAB9AAAAAEUm9sZQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAA==",
+        "AAAAAAAAABpHZXQgYWRkcmVzcyBob2xkaW5nIGEgcm9sZQAAAAAACGdldF9yb2xlAAAAAQAAAAAAAAAEcm9sZQAAB9AAAAAEUm9sZQAAAAEAAAAT",
+        "AAAAAAAAARFQYXVzZSB0aGUgY29udHJhY3QgKHBhdXNlciBvbmx5KQoKQmxvY2tzIG5ldyByb3VuZHMsIGJldHMgYW5kIGxpcXVpZGl0eSBkZXBvc2l0cywgYW5kIG1vdmluZyBhIHJvdW5kIHRvCml0cyByZXZlYWwgcGhhc2Ugb3Igc3RhcnQuIENhc2gtb3V0cywgc2VlZCByZXZlYWxzLCBmaW5hbGl6YXRpb24sCnJlZnVuZHMsIGNsYWltcywgd2l0aGRyYXdhbHMgYW5kIHZpZXdzIGtlZXAgd29ya2luZyBzbyBwbGF5ZXJzIGFuZApsaXF1aWRpdHkgcHJvdmlkZXJzIGNhbiBhbHdheXMgZXhpdC4AAAAAAAAFcGF1c2UAAAAAAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAACJVbnBhdXNlIHRoZSBjb250cmFjdCAocGF1c2VyIG9ubHkpAAAAAAAHdW5wYXVzZQAAAAAAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAB9DaGVjayBpZiB0aGUgY29udHJhY3QgaXMgcGF1c2VkAAAAAAZwYXVzZWQAAAAAAAAAAAABAAAAAQ==",
+        "AAAAAAAAADRHZXQgdG9rZW4gY29udHJhY3QgYWRkcmVzcyB1c2VkIGZvciBiZXRzIGFuZCBwYXlvdXRzAAAABXRva2VuAAAAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAHFVcGRhdGUgZ2FtZSBjb25maWd1cmF0aW9uICh0cmVhc3VyZXIgb25seSkKClJvdW5kcyBrZWVwIHRoZSBob3VzZSBlZGdlIGFuZCBtYXggbXVsdGlwbGllciB0aGV5IHdlcmUgY3JlYXRlZCB3aXRoLgAAAAAAAApzZXRfY29uZmlnAAAAAAABAAAAAAAAAAZjb25maWcAAAAAB9AAAAAGQ29uZmlnAAAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAABZHZXQgZ2FtZSBjb25maWd1cmF0aW9uAAAAAAAKZ2V0X2NvbmZpZwAAAAAAAAAAAAEAAAfQAAAABkNvbmZpZwAA",
+        "AAAAAAAAARFEZXBvc2l0IGxpcXVpZGl0eSBpbnRvIHRoZSBob3VzZSBiYW5rcm9sbAoKTWludHMgTFAgc2hhcmVzIHByaWNlZCBhdCB0aGUgYmFua3JvbGwncyBuZXQgYXNzZXQgdmFsdWUsIHNvIGV2ZXJ5CnByb3ZpZGVyIHNoYXJlcyB0aGUgaG91c2UgUCZMIGluIHByb3BvcnRpb24gdG8gdGhlaXIgc2hhcmVzLiBMb2NrZWQKd2hpbGUgcm91bmRzIGFyZSBvcGVuLCBzaW5jZSB0aGVpciBvdXRjb21lIGlzbid0IHNldHRsZWQgeWV0LCBhbmQKd2hpbGUgdGhlIGNvbnRyYWN0IGlzIHBhdXNlZC4AAAAAAAARZGVwb3NpdF9saXF1aWRpdHkAAAAAAAACAAAAAAAAAAhwcm92aWRlcgAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAASNXaXRoZHJhdyBsaXF1aWRpdHkgYnkgYnVybmluZyBMUCBzaGFyZXMKClBhaWQgb3V0IHJpZ2h0IGF3YXkgd2hlbiBubyByb3VuZCBpcyBvcGVuLiBPdGhlcndpc2UgdGhlIHNoYXJlcyBhcmUKcXVldWVkIGFuZCBidXJuZWQgb25jZSB0aGUgbGFzdCBvcGVuIHJvdW5kIGlzIGZpbmFsaXplZCBvciBjYW5jZWxsZWQsCmF0IHRoZSBzZXR0bGVkIG5ldCBhc3NldCB2YWx1ZTsgcmV0dXJucyAwIGluIHRoYXQgY2FzZSBhbmQgdGhlIGFtb3VudAppcyB0aGVuIGNvbGxlY3RlZCB3aXRoIGBjbGFpbV93aXRoZHJhd2FsYC4AAAAAEndpdGhkcmF3X2xpcXVpZGl0eQAAAAAAAgAAAAAAAAAIcHJvdmlkZXIAAAATAAAAAAAAAAZzaGFyZXMAAAAAAAsAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAAI5QYXkgb3V0IGEgcHJvdmlkZXIncyBzZXR0bGVkIHdpdGhkcmF3YWxzCgpRdWV1ZWQgd2l0aGRyYXdhbHMgYmVjb21lIGNsYWltYWJsZSBvbmNlIHRoZSBsYXN0IG9wZW4gcm91bmQgY2xvc2VzLgpSZXR1cm5zIHRoZSBhbW91bnQgdHJhbnNmZXJyZWQuAAAAAAAQY2xhaW1fd2l0aGRyYXdhbAAAAAEAAAAAAAAACHByb3ZpZGVyAAAAEwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAADhHZXQgcHJpY2Ugb2Ygb25lIExQIHNoYXJlIGluIHRva2VuIHVuaXRzLCBzY2FsZWQgYnkgMTBeNwAAAA9nZXRfc2hhcmVfcHJpY2UAAAAAAAAAAAEAAAAL",
+        "AAAAAAAAACBHZXQgTFAgc2hhcmVzIGhlbGQgYnkgYSBwcm92aWRlcgAAAA1nZXRfbHBfc2hhcmVzAAAAAAAAAQAAAAAAAAAIcHJvdmlkZXIAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAC5HZXQgTFAgc2hhcmVzIGEgcHJvdmlkZXIgcXVldWVkIGZvciB3aXRoZHJhd2FsAAAAAAAWZ2V0X3BlbmRpbmdfd2l0aGRyYXdhbAAAAAAAAQAAAAAAAAAIcHJvdmlkZXIAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAD1HZXQgdG9rZW4gYW1vdW50IGEgcHJvdmlkZXIgY2FuIGNsYWltIHdpdGggYGNsYWltX3dpdGhkcmF3YWxgAAAAAAAAGGdldF9jbGFpbWFibGVfd2l0aGRyYXdhbAAAAAEAAAAAAAAACHByb3ZpZGVyAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAMtQYXkgb3V0IHRva2VucyBjcmVkaXRlZCB0byBhIHBsYXllcgoKUmVmdW5kcyBvZiBmb3JmZWl0ZWQgYmV0cyBhcmUgY3JlZGl0ZWQgaW5zdGVhZCBvZiB0cmFuc2ZlcnJlZCwgYW5kIHNvCmFyZSBhdXRvIGNhc2gtb3V0cyB0aGF0IGNvdWxkbid0IGJlIHRyYW5zZmVycmVkIGF0IGZpbmFsaXphdGlvbi4KUmV0dXJucyB0aGUgYW1vdW50IHRyYW5zZmVycmVkLgAAAAAMY2xhaW1fcGF5b3V0AAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAAD5HZXQgdG9rZW5zIGNyZWRpdGVkIHRvIGEgcGxheWVyLCBjb2xsZWN0ZWQgd2l0aCBgY2xhaW1fcGF5b3V0YAAAAAAAEWdldF91bnBhaWRfcGF5b3V0AAAAAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAVlDcmVhdGUgdGhlIG5leHQgcm91bmQgKG9wZXJhdG9yIG9ubHkpCgpTZWN1cml0eTogT25seSB0aGUgb3BlcmF0b3IgY2FuIGNyZWF0ZSByb3VuZHMgdG8gcHJldmVudCBzcGFtCgpSb3VuZCBpZHMgYXJlIHNlcXVlbnRpYWwsIHN0YXJ0aW5nIGF0IDEuIEEgbmV3IHJvdW5kIGNhbiBvbmx5IGJlCmNyZWF0ZWQgb25jZSB0aGUgcHJldmlvdXMgb25lIGlzIGZpbmFsaXplZCBvciBjYW5jZWxsZWQuIFdoaWxlIGEgc2VlZApjaGFpbiBoYXMgdW5yZXZlYWxlZCBzZWVkcywgcm91bmRzIG11c3QgY29tZSBmcm9tCmBjcmVhdGVfY2hhaW5lZF9yb3VuZGAgc28gdGhlIGNoYWluJ3MgaGlzdG9yeSBoYXMgbm8gZ2Fwcy4AAAAAAAAMY3JlYXRlX3JvdW5kAAAAAQAAAAAAAAAQc2VydmVyX3NlZWRfaGFzaAAAA+4AAAAgAAAAAQAAA+kAAAfQAAAABVJvdW5kAAAAAAAAAw==",
+        "AAAAAAAAAR5Db21taXQgdG8gYSBjaGFpbiBvZiBgbGVuZ3RoYCBzZXJ2ZXIgc2VlZHMgKG9wZXJhdG9yIG9ubHkpCgpSb3VuZHMgY3JlYXRlZCB3aXRoIGBjcmVhdGVfY2hhaW5lZF9yb3VuZGAgdGhlbiB1c2UgdGhlIGNoYWluJ3MgbGlua3MKYXMgY29tbWl0bWVudHMsIHNvIG5vIGZyZXNoIGhhc2ggaXMgbmVlZGVkIHBlciByb3VuZCBhbmQgdGhlIHdob2xlCmhpc3RvcnkgaXMgbGlua2VkLiBBIHByZXZpb3VzIGNoYWluIGNhbiBvbmx5IGJlIHJlcGxhY2VkIG9uY2UgYWxsIGl0cwpzZWVkcyBhcmUgcmV2ZWFsZWQuAAAAAAARY29tbWl0X3NlZWRfY2hhaW4AAAAAAAACAAAAAAAAAA10ZXJtaW5hbF9oYXNoAAAAAAAD7gAAACAAAAAAAAAABmxlbmd0aAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAACBHZXQgY29tbWl0dGVkIHNlZWQgY2hhaW4sIGlmIGFueQAAAA5nZXRfc2VlZF9jaGFpbgAAAAAAAAAAAAEAAAPoAAAH0AAAAAlTZWVkQ2hhaW4AAAA=",
+        "AAAAAAAAARRDcmVhdGUgdGhlIG5leHQgcm91bmQgY29tbWl0dGVkIHRvIHRoZSBuZXh0IGxpbmsgb2YgdGhlIHNlZWQgY2hhaW4KKG9wZXJhdG9yIG9ubHkpCgpUaGUgcm91bmQncyBgc2VydmVyX3NlZWRfaGFzaGAgaXMgdGhlIGxhc3QgcmV2ZWFsZWQgbGluaywgc28KYHN0YXJ0X3JvdW5kYCBtdXN0IHJldmVhbCBpdHMgcHJlaW1hZ2UuIEEgcm91bmQgY2FuY2VsbGVkIGJlZm9yZSBpdApzdGFydHMgcmV2ZWFscyBub3RoaW5nIGFuZCB0aGUgbmV4dCByb3VuZCByZXVzZXMgdGhlIHNhbWUgbGluay4AAAAUY3JlYXRlX2NoYWluZWRfcm91bmQAAAAAAAAAAQAAA+kAAAfQAAAABVJvdW5kAAAAAAAAAw==",
+        "AAAAAAAAAT5TdG9wIHRha2luZyBiZXRzIGFuZCBvcGVuIHRoZSByZXZlYWwgcGhhc2UgKG9wZXJhdG9yIG9ubHkpCgpDb21taXR0ZWQgY2xpZW50IHNlZWRzIGNhbiBvbmx5IGJlIHJldmVhbGVkIGFmdGVyIHRoaXMsIHNvIG5vYm9keSBjYW4KcGljayBhIHNlZWQgYWZ0ZXIgc2VlaW5nIHRoZSByZXZlYWxlZCBvbmVzLiBUaGUgcm91bmQgY2FuJ3Qgc3RhcnQgZm9yCmBtaW5fcmV2ZWFsX3dpbmRvd2Agc2Vjb25kcywgZ2l2aW5nIHBsYXllcnMgdGltZSB0byByZXZlYWwuIEJldHMgc3RpbGwKdW5yZXZlYWxlZCB3aGVuIHRoZSByb3VuZCBzdGFydHMgYXJlIGZvcmZlaXRlZC4AAAAAAA1jbG9zZV9iZXR0aW5nAAAAAAAAAQAAAAAAAAAIcm91bmRfaWQAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAQtTdGFydCB0aGUgcm91bmQgd2l0aCBzZXJ2ZXIgc2VlZCAob3BlcmF0b3Igb25seSkKClNlY3VyaXR5OgotIFNlcnZlciBzZWVkIG11c3QgbWF0Y2ggdGhlIGNvbW1pdHRlZCBoYXNoCi0gQ3Jhc2ggbXVsdGlwbGllciBpcyBkZXJpdmVkIG9uLWNoYWluIGZyb20gdGhlIHNlcnZlciBzZWVkIGFuZCB0aGUKY29sbGVjdGVkIGNsaWVudCBzZWVkcywgc28gbm9ib2R5IGNhbiBwaWNrIGl0Ci0gUmV2ZWFsZWQgc2VydmVyIHNlZWQgaXMgc3RvcmVkIGZvciB2ZXJpZmljYXRpb24AAAAAC3N0YXJ0X3JvdW5kAAAAAAIAAAAAAAAACHJvdW5kX2lkAAAABgAAAAAAAAALc2VydmVyX3NlZWQAAAAD7gAAACAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAZhQbGFjZSBhIGJldCBpbiB0aGUgY3VycmVudCByb3VuZAoKYGF1dG9fY2FzaF9vdXRgIG9wdGlvbmFsbHkgc2V0cyBhIHRhcmdldCBtdWx0aXBsaWVyICgxMDAgPSAxLjAweCk6IGlmCnRoZSByb3VuZCByZWFjaGVzIGl0LCB0aGUgYmV0IGlzIGNhc2hlZCBvdXQgYXQgdGhlIHRhcmdldCB3aXRob3V0IHRoZQpwbGF5ZXIgc2VuZGluZyBhbm90aGVyIHRyYW5zYWN0aW9uLgoKU2VjdXJpdHk6Ci0gQ2hlY2tzIHBsYXllciBiYWxhbmNlCi0gVmFsaWRhdGVzIGJldCBhbW91bnQgKG1pbi9tYXgpCi0gQ2hlY2tzIHRoZSBiYW5rcm9sbCBjYW4gY292ZXIgdGhlIHJvdW5kJ3Mgd29yc3QtY2FzZSBwYXlvdXQKLSBQcmV2ZW50cyBiZXR0aW5nIGFmdGVyIHJvdW5kIHN0YXJ0ZWQKLSBVc2VzIHRva2VuIHRyYW5zZmVyIGZvciBYTE0AAAAJcGxhY2VfYmV0AAAAAAAABQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAhyb3VuZF9pZAAAAAYAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAALY2xpZW50X3NlZWQAAAAD7gAAACAAAAAAAAAADWF1dG9fY2FzaF9vdXQAAAAAAAPoAAAABgAAAAEAAAPpAAAABgAAAAM=",
+        "AAAAAAAAAVdQbGFjZSBhIGJldCB3aXRoIGEgY29tbWl0bWVudCB0byBpdHMgY2xpZW50IHNlZWQgaW5zdGVhZCBvZiB0aGUgc2VlZAoKYHNlZWRfY29tbWl0bWVudGAgaXMgYHNoYTI1NihjbGllbnRfc2VlZClgLiBUaGUgcGxheWVyIHJldmVhbHMgdGhlCnNlZWQgd2l0aCBgcmV2ZWFsX2NsaWVudF9zZWVkYCBiZWZvcmUgdGhlIHJvdW5kIHN0YXJ0czsgZXZlcnkgcmV2ZWFsZWQKc2VlZCBpcyBtaXhlZCBpbnRvIHRoZSBjcmFzaCBwb2ludC4gQmV0cyBzdGlsbCB1bnJldmVhbGVkIHdoZW4gdGhlCnJvdW5kIHN0YXJ0cyBhcmUgdm9pZGVkIGFuZCByZWZ1bmRlZCBtaW51cyBgdW5yZXZlYWxlZF9wZW5hbHR5X2Jwc2AuAAAAABNwbGFjZV9jb21taXR0ZWRfYmV0AAAAAAUAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAIcm91bmRfaWQAAAAGAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAD3NlZWRfY29tbWl0bWVudAAAAAPuAAAAIAAAAAAAAAANYXV0b19jYXNoX291dAAAAAAAA+gAAAAGAAAAAQAAA+kAAAAGAAAAAw==",
+        "AAAAAAAAAG9SZXZlYWwgdGhlIGNsaWVudCBzZWVkIG9mIGEgY29tbWl0dGVkIGJldAoKT25seSBhY2NlcHRlZCBvbmNlIHRoZSBvcGVyYXRvciBjbG9zZWQgYmV0dGluZyB3aXRoIGBjbG9zZV9iZXR0aW5nYC4AAAAAEnJldmVhbF9jbGllbnRfc2VlZAAAAAAAAwAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZiZXRfaWQAAAAAAAYAAAAAAAAAC2NsaWVudF9zZWVkAAAAA+4AAAAgAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAP9DYXNoIG91dCBhIGJldCBhdCBjdXJyZW50IG11bHRpcGxpZXIKClNlY3VyaXR5OgotIFZlcmlmaWVzIGJldCBvd25lcnNoaXAKLSBDaGVja3MgYmV0IGlzIGFjdGl2ZQotIENvbXB1dGVzIHRoZSBsaXZlIG11bHRpcGxpZXIgb24tY2hhaW4gZnJvbSB0aGUgbGVkZ2VyIHRpbWVzdGFtcAotIFZhbGlkYXRlcyBtdWx0aXBsaWVyIGhhc24ndCBjcmFzaGVkCi0gQ2FsY3VsYXRlcyBwYXlvdXQgd2l0aCBob3VzZSBlZGdlCi0gUHJldmVudHMgcmUtZW50cnkAAAAACGNhc2hfb3V0AAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZiZXRfaWQAAAAAAAYAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAASlDYXNoIG91dCBwYXJ0IG9mIGEgYmV0LCBsZWF2aW5nIHRoZSByZXN0IG9mIHRoZSBzdGFrZSByaWRpbmcKCmBmcmFjdGlvbl9icHNgIGlzIHRoZSBzaGFyZSBvZiB0aGUgc3Rha2Ugc3RpbGwgcmlkaW5nIHRvIHNldHRsZSBhdCB0aGUKbGl2ZSBtdWx0aXBsaWVyOyAxMDAwMCBzZXR0bGVzIHRoZSB3aG9sZSBiZXQgbGlrZSBgY2FzaF9vdXRgLiBFYWNoCnBhcnRpYWwgc2V0dGxlbWVudCBpcyByZWNvcmRlZCBvbiB0aGUgYmV0LCB1cCB0bwpgTUFYX1BBUlRJQUxfQ0FTSF9PVVRTYC4gUmV0dXJucyB0aGUgbmV0IHBheW91dC4AAAAAAAAQY2FzaF9vdXRfcGFydGlhbAAAAAMAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAGYmV0X2lkAAAAAAAGAAAAAAAAAAxmcmFjdGlvbl9icHMAAAAEAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAAZ9GaW5hbGl6ZSB0aGUgcm91bmQgKG9wZXJhdG9yIG9ubHkpCgpTZWN1cml0eToKLSBPbmx5IHRoZSBvcGVyYXRvciBjYW4gZmluYWxpemUKLSBWZXJpZmllcyByb3VuZCBpcyBpbiBwcm9ncmVzcyBhbmQgdGhlIGN1cnZlIHJlYWNoZWQgdGhlIGNyYXNoIHBvaW50Ci0gQ2FzaGVzIG91dCBiZXRzIHdob3NlIGF1dG8gY2FzaC1vdXQgdGFyZ2V0IHdhcyByZWFjaGVkOyBhIHBheW91dAp0aGF0IGNhbid0IGJlIHRyYW5zZmVycmVkIGlzIGNyZWRpdGVkIGZvciBgY2xhaW1fcGF5b3V0YCBpbnN0ZWFkCi0gTWFya3MgYWxsIHVuY2FzaGVkIGJldHMgYXMgbG9zdCwga2VlcGluZyB0aGVpciBwYXJ0aWFsIGNhc2gtb3V0cwotIFJlY29yZHMgZmluYWwgc3RhdHMgKHRvdGFsIHBheW91dCwgd2lubmVycywgbG9zZXJzLCBob3VzZSBwcm9maXQpAAAAAA5maW5hbGl6ZV9yb3VuZAAAAAAAAQAAAAAAAAAIcm91bmRfaWQAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAALxNaWdyYXRlIHJvdW5kcyBhbmQgYmV0cyBzdG9yZWQgdW5kZXIgYmFyZSBgdTY0YCBpZHMgKGFkbWluIG9ubHkpCgpLZXB0IGZvciBleGlzdGluZyB0b29saW5nLCBzYW1lIGFzIGBtaWdyYXRlYCB3aXRob3V0IGEgdG9rZW4sIHdoaWNoCmFsc28gcmV3cml0ZXMgdGhlIG1vdmVkIGVudHJpZXMgaW4gdGhlIGN1cnJlbnQgbGF5b3V0LgAAAA9taWdyYXRlX3N0b3JhZ2UAAAAAAQAAAAAAAAADaWRzAAAAA+oAAAAGAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAAKZSZXBsYWNlIHRoZSBjb250cmFjdCBjb2RlLCBrZWVwaW5nIGFsbCBzdG9yYWdlIChhZG1pbiBvbmx5KQoKVGhlIG5ldyBjb2RlIHJ1bnMgZnJvbSB0aGUgbmV4dCBpbnZvY2F0aW9uLiBJZiBpdCBjaGFuZ2VzIHRoZSBzdG9yYWdlCmxheW91dCwgY2FsbCBgbWlncmF0ZWAgcmlnaHQgYWZ0ZXIuAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
+        "AAAAAAAAAjZCcmluZyBzdG9yYWdlIHRvIHRoZSBjdXJyZW50IHNjaGVtYSB2ZXJzaW9uIChhZG1pbiBvbmx5KQoKTW92ZXMgZW50cmllcyB1bmRlciBiYXJlIGB1NjRgIGlkcyB0byB0aGVpciB0eXBlZCBrZXlzLCByZXdyaXRlcwpyb3VuZHMgYW5kIGJldHMgc3RvcmVkIGluIG9sZGVyIGxheW91dHMgYW5kIHVwZ3JhZGVzIHRoZSBwb29sLiBMYXJnZQpkZXBsb3ltZW50cyBjYW4gcGFzcyB0aGUgaWRzIGluIGJhdGNoZXM7IGV2ZXJ5IGNhbGwgaXMgaWRlbXBvdGVudC4KUmV0dXJucyBob3cgbWFueSByb3VuZHMgYW5kIGJldHMgd2VyZSByZXdyaXR0ZW4uCgpWZXJzaW9uIDEgZGVwbG95bWVudHMgb25seSBzdG9yZWQgdGhlIGFkbWluIGFuZCB0aGUgcG9vbDogYHRva2VuYApzZXRzIHRoZSBiZXQgdG9rZW4gaWYgbm9uZSBpcyBzdG9yZWQgeWV0IChpdCBpcyBpZ25vcmVkIG90aGVyd2lzZSksCmFuZCB1bmFzc2lnbmVkIHJvbGVzIGdvIHRvIHRoZSBhZG1pbiwgYXMgaW4gdGhlIGNvbnN0cnVjdG9yLiBMZWdhY3kKcm91bmRzIG11c3QgaGF2ZSBlbmRlZCBhbmQgbGVnYWN5IGJldHMgbXVzdCBiZSBzZXR0bGVkLgAAAAAAB21pZ3JhdGUAAAAAAgAAAAAAAAADaWRzAAAAA+oAAAAGAAAAAAAAAAV0b2tlbgAAAAAAA+gAAAATAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAABpHZXQgc3RvcmFnZSBzY2hlbWEgdmVyc2lvbgAAAAAAB3ZlcnNpb24AAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAOBFeHRlbmQgdGhlIFRUTCBvZiBhIHJvdW5kIGFuZCBpdHMgYmV0cyAoYW55b25lIGNhbiBjYWxsKQoKS2VlcGVycyBjYWxsIHRoaXMgZm9yIHJvdW5kcyB0aGF0IGFyZSBzdGlsbCBvcGVuIG9yIGhhdmUgcmVmdW5kcwp0byBjbGFpbS4gRW5kZWQgcm91bmRzIGdldCBhIGZpeGVkIFRUTCB3aGVuIGZpbmFsaXplZCBhbmQgY2FuJ3QgYmUKYnVtcGVkLCBzbyB0aGV5IGV4cGlyZSBvbiBwdXJwb3NlLgAAAApidW1wX3JvdW5kAAAAAAABAAAAAAAAAAhyb3VuZF9pZAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAADpHZXQgdGhlIGxhdGVzdCByb3VuZCBpZiBpdCBpcyBzdGlsbCB3YWl0aW5nIG9yIGluIHByb2dyZXNzAAAAAAANY3VycmVudF9yb3VuZAAAAAAAAAAAAAABAAAD6AAAB9AAAAAFUm91bmQAAAA=",
         "AAAAAAAAABFHZXQgcm91bmQgZGV0YWlscwAAAAAAAAlnZXRfcm91bmQAAAAAAAABAAAAAAAAAAhyb3VuZF9pZAAAAAYAAAABAAAD6QAAB9AAAAAFUm91bmQAAAAAAAAD",
+        "AAAAAAAAAf1DYW5jZWwgYSByb3VuZCB0aGF0IGhhc24ndCBzdGFydGVkIChvcGVyYXRvciBvbmx5KQoKRXZlcnkgYWN0aXZlIGJldCBvZiBhIGNhbmNlbGxlZCByb3VuZCBjYW4gYmUgcmVmdW5kZWQgd2l0aCBgY2xhaW1fcmVmdW5kYC4KVGhlIG9wZXJhdG9yIGtub3dzIHRoZSBzZXJ2ZXIgc2VlZCwgc28gb25jZSBjbGllbnQgc2VlZHMgYXJlIGNvbGxlY3RlZAp0aGV5IGNhbiBjb21wdXRlIHRoZSBjcmFzaCBwb2ludCBhbmQgd291bGQgb25seSBjYW5jZWwgcm91bmRzIHRoZXknZApsb3NlLiBDYW5jZWxsaW5nIGlzIHRoZXJlZm9yZSBsaW1pdGVkIHRvIHJvdW5kcyB3aXRob3V0IGNsaWVudCBzZWVkcywKb3Igcm91bmRzIHdob3NlIGNyYXNoIHBvaW50IGFsc28gbWl4ZXMgaW4gbGVkZ2VyIGVudHJvcHkgZHJhd24gYXQKc3RhcnQuIE90aGVyIHJvdW5kcywgYW5kIHJvdW5kcyBpbiBwcm9ncmVzcywgY2FuIG9ubHkgYmUgY2FuY2VsbGVkCnRocm91Z2ggdGhlIHRpbWVvdXRzIG9mIGBjbGFpbV9yZWZ1bmRgLgAAAAAAAAxjYW5jZWxfcm91bmQAAAABAAAAAAAAAAhyb3VuZF9pZAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAd9SZWZ1bmQgdGhlIHN0YWtlIG9mIGFuIGFjdGl2ZSBiZXQgKGFueW9uZSBjYW4gY2FsbCkKCkF2YWlsYWJsZSBvbmNlIHRoZSByb3VuZCBpcyBjYW5jZWxsZWQsIG9yIHdoZW4gaXQgaXMgc3R1Y2s6IG5vdCBzdGFydGVkCmB3YWl0aW5nX3RpbWVvdXRgIHNlY29uZHMgYWZ0ZXIgY3JlYXRpb24gb3Igbm90IGZpbmFsaXplZApgaW5fcHJvZ3Jlc3NfdGltZW91dGAgc2Vjb25kcyBhZnRlciBzdGFydCwgYXMgY29uZmlndXJlZCB3aGVuIHRoZSByb3VuZAp3YXMgY3JlYXRlZC4gQSBzdHVjayByb3VuZCBpcyBjYW5jZWxsZWQgYnkgdGhlIGZpcnN0IHJlZnVuZCBzbyBpdCBjYW4ndApiZSByZXN1bWVkIGFmdGVyd2FyZHMuIFRoZSBzdGFrZSBhbHdheXMgZ29lcyBiYWNrIHRvIHRoZSBiZXQncyBwbGF5ZXIsCm1pbnVzIGFueSBwYXJ0IGFscmVhZHkgc2V0dGxlZCBieSBwYXJ0aWFsIGNhc2gtb3V0czsgcmV0dXJucyB0aGUKcmVmdW5kZWQgYW1vdW50LgAAAAAMY2xhaW1fcmVmdW5kAAAAAQAAAAAAAAAGYmV0X2lkAAAAAAAGAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAAJ5WZXJpZnkgYSByb3VuZCdzIHByb3ZhYmx5IGZhaXIgb3V0Y29tZQoKUmVjb21wdXRlcyB0aGUgc2VlZCBoYXNoIGFuZCB0aGUgY3Jhc2ggbXVsdGlwbGllciBmcm9tIHRoZSBzdG9yZWQgc2VlZHMKc28gdmVyaWZpZXJzIGRvbid0IGhhdmUgdG8gdHJ1c3QgdGhlIGZyb250ZW5kLgAAAAAADHZlcmlmeV9yb3VuZAAAAAEAAAAAAAAACHJvdW5kX2lkAAAABgAAAAEAAAPpAAAH0AAAABFSb3VuZFZlcmlmaWNhdGlvbgAAAAAAAAM=",
+        "AAAAAAAAAH5HZXQgdGhlIGxpdmUgbXVsdGlwbGllciBvZiBhIHJvdW5kIGluIHByb2dyZXNzICgxMDAgPSAxLjAweCkKCkZvbGxvd3MgdGhlIHB1Ymxpc2hlZCBncm93dGggY3VydmUgYW5kIHN0b3BzIGF0IHRoZSBjcmFzaCBwb2ludC4AAAAAAA5nZXRfbXVsdGlwbGllcgAAAAAAAQAAAAAAAAAIcm91bmRfaWQAAAAGAAAAAQAAA+kAAAAGAAAAAw==",
         "AAAAAAAAAA9HZXQgYmV0IGRldGFpbHMAAAAAB2dldF9iZXQAAAAAAQAAAAAAAAAGYmV0X2lkAAAAAAAGAAAAAQAAA+kAAAfQAAAAA0JldAAAAAAD",
+        "AAAAAAAAAGNHZXQgYSBwYWdlIG9mIHRoZSBiZXRzIHBsYWNlZCBpbiBhIHJvdW5kLCBpbiBwbGFjZW1lbnQgb3JkZXIKCmBsaW1pdGAgaXMgY2FwcGVkIGF0IGBNQVhfUEFHRV9TSVpFYC4AAAAADmdldF9yb3VuZF9iZXRzAAAAAAADAAAAAAAAAAhyb3VuZF9pZAAAAAYAAAAAAAAABmN1cnNvcgAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+kAAAPqAAAH0AAAAANCZXQAAAAAAw==",
+        "AAAAAAAAAHdHZXQgdGhlIGJldHMgY2FzaGVkIG91dCBpbiBhIHJvdW5kLCBoaWdoZXN0IHBheW91dCBmaXJzdAoKQmV0cyB3aXRoIHRoZSBzYW1lIHBheW91dCBrZWVwIHRoZSBvcmRlciB0aGV5IHdlcmUgcGxhY2VkIGluLgAAAAASZ2V0X3JvdW5kX2Nhc2hvdXRzAAAAAAABAAAAAAAAAAhyb3VuZF9pZAAAAAYAAAABAAAD6QAAA+oAAAfQAAAAA0JldAAAAAAD",
+        "AAAAAAAAADtHZXQgdGhlIGJldHMgYSBwbGF5ZXIgcGxhY2VkIGluIGEgcm91bmQsIGluIHBsYWNlbWVudCBvcmRlcgAAAAAVZ2V0X3BsYXllcl9yb3VuZF9iZXRzAAAAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAhyb3VuZF9pZAAAAAYAAAABAAAD6gAAB9AAAAADQmV0AA==",
+        "AAAAAAAAAN1HZXQgYSBwYWdlIG9mIGEgcGxheWVyJ3MgYmV0cywgb2xkZXN0IGZpcnN0CgpgY3Vyc29yYCBpcyB0aGUgcG9zaXRpb24gaW4gdGhlIHBsYXllcidzIGhpc3RvcnkgdG8gc3RhcnQgZnJvbSBhbmQKYGxpbWl0YCBpcyBjYXBwZWQgYXQgYE1BWF9QQUdFX1NJWkVgLiBVc2UgYGdldF9wbGF5ZXJfYmV0X2NvdW50YCB0bwpwYWdlIGZyb20gdGhlIG1vc3QgcmVjZW50IGJldHMgYmFja3dhcmRzLgAAAAAAAA9nZXRfcGxheWVyX2JldHMAAAAAAwAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZjdXJzb3IAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAH0AAAAANCZXQA",
+        "AAAAAAAAACJHZXQgbnVtYmVyIG9mIGJldHMgYSBwbGF5ZXIgcGxhY2VkAAAAAAAUZ2V0X3BsYXllcl9iZXRfY291bnQAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAABA==",
+        "AAAAAAAAAB5HZXQgbGlmZXRpbWUgc3RhdHMgb2YgYSBwbGF5ZXIAAAAAABBnZXRfcGxheWVyX3N0YXRzAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAB9AAAAALUGxheWVyU3RhdHMA",
         "AAAAAAAAABNHZXQgcG9vbCBzdGF0aXN0aWNzAAAAAAhnZXRfcG9vbAAAAAAAAAABAAAH0AAAAARQb29s" ]),
       options
     )
   }
   public readonly fromJSON = {
     admin: this.txFromJSON<string>,
+        propose_admin: this.txFromJSON<null>,
+        accept_admin: this.txFromJSON<Result<void>>,
+        pending_admin: this.txFromJSON<Option<string>>,
+        set_role: this.txFromJSON<null>,
+        get_role: this.txFromJSON<string>,
+        pause: this.txFromJSON<Result<void>>,
+        unpause: this.txFromJSON<Result<void>>,
+        paused: this.txFromJSON<boolean>,
+        token: this.txFromJSON<string>,
+        set_config: this.txFromJSON<Result<void>>,
+        get_config: this.txFromJSON<Config>,
+        deposit_liquidity: this.txFromJSON<Result<i128>>,
+        withdraw_liquidity: this.txFromJSON<Result<i128>>,
+        claim_withdrawal: this.txFromJSON<Result<i128>>,
+        get_share_price: this.txFromJSON<i128>,
+        get_lp_shares: this.txFromJSON<i128>,
+        get_pending_withdrawal: this.txFromJSON<i128>,
+        get_claimable_withdrawal: this.txFromJSON<i128>,
+        claim_payout: this.txFromJSON<Result<i128>>,
+        get_unpaid_payout: this.txFromJSON<i128>,
         create_round: this.txFromJSON<Result<Round>>,
+        commit_seed_chain: this.txFromJSON<Result<void>>,
+        get_seed_chain: this.txFromJSON<Option<SeedChain>>,
+        create_chained_round: this.txFromJSON<Result<Round>>,
+        close_betting: this.txFromJSON<Result<void>>,
         start_round: this.txFromJSON<Result<void>>,
         place_bet: this.txFromJSON<Result<u64>>,
+        place_committed_bet: this.txFromJSON<Result<u64>>,
+        reveal_client_seed: this.txFromJSON<Result<void>>,
         cash_out: this.txFromJSON<Result<i128>>,
+        cash_out_partial: this.txFromJSON<Result<i128>>,
         finalize_round: this.txFromJSON<Result<void>>,
+        migrate_storage: this.txFromJSON<Result<u32>>,
+        upgrade: this.txFromJSON<null>,
+        migrate: this.txFromJSON<Result<u32>>,
+        version: this.txFromJSON<u32>,
+        bump_round: this.txFromJSON<Result<void>>,
+        current_round: this.txFromJSON<Option<Round>>,
         get_round: this.txFromJSON<Result<Round>>,
+        cancel_round: this.txFromJSON<Result<void>>,
+        claim_refund: this.txFromJSON<Result<i128>>,
+        verify_round: this.txFromJSON<Result<RoundVerification>>,
+        get_multiplier: this.txFromJSON<Result<u64>>,
         get_bet: this.txFromJSON<Result<Bet>>,
+        get_round_bets: this.txFromJSON<Result<Array<Bet>>>,
+        get_round_cashouts: this.txFromJSON<Result<Array<Bet>>>,
+        get_player_round_bets: this.txFromJSON<Array<Bet>>,
+        get_player_bets: this.txFromJSON<Array<Bet>>,
+        get_player_bet_count: this.txFromJSON<u32>,
+        get_player_stats: this.txFromJSON<PlayerStats>,
         get_pool: this.txFromJSON<Pool>
   }
 }
\ No newline at end of file
@@ -5,17 +5,17 @@ import { useBalloonFlyContext } from "../../contexts/BalloonFlyContext";
 const BettingControls: React.FC = () => {
   const { placeBet, cashOut, userBet, loading, error, isFlying } = useBalloonFlyContext();
 
-  const handleBet1 = async (amount: number) => {
+  const handleBet1 = async (amount: number, autoCashOut?: number) => {
     try {
-      await placeBet(amount);
+      await placeBet(amount, autoCashOut);
     } catch (err) {
       console.error("Error placing bet:", err);
     }
   };
 
-  const handleBet2 = async (amount: number) => {
+  const handleBet2 = async (amount: number, autoCashOut?: number) => {
     try {
-      await placeBet(amount);
+      await placeBet(amount, autoCashOut);
     } catch (err) {
       console.error("Error placing bet:", err);
     }
@@ -37,7 +37,7 @@ const BettingControls: React.FC = () => {
     }
   };
 
-  const hasActiveBet = userBet && userBet.status === "Active" && isFlying;
+  const hasActiveBet = userBet && userBet.status.tag === "Active" && isFlying;
 
   return (
     <div style={{
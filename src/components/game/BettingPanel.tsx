@@ -2,7 +2,7 @@ import React, { useState } from "react";
 
 interface BettingPanelProps {
   isActive?: boolean;
-  onBet?: (amount: number) => void;
+  onBet?: (amount: number, autoCashOut?: number) => void;
   onCashOut?: () => void;
   loading?: boolean;
 }
@@ -15,6 +15,7 @@ const BettingPanel: React.FC<BettingPanelProps> = ({
 }) => {
   const [betAmount, setBetAmount] = useState(1.0);
   const [activeTab, setActiveTab] = useState<"manual" | "auto">("manual");
+  const [autoCashOut, setAutoCashOut] = useState(2.0);
 
   const quickAmounts = [10, 20, 50, 100];
 
@@ -34,7 +35,7 @@ const BettingPanel: React.FC<BettingPanelProps> = ({
     if (isActive && onCashOut) {
       onCashOut();
     } else if (onBet) {
-      onBet(betAmount);
+      onBet(betAmount, activeTab === "auto" ? autoCashOut : undefined);
     }
   };
 
@@ -222,6 +223,40 @@ const BettingPanel: React.FC<BettingPanelProps> = ({
               </button>
             ))}
           </div>
+
+          {/* Auto Cash-Out Target, settled by the contract when the round reaches it */}
+          {activeTab === "auto" && (
+            <div style={{
+              display: "flex",
+              alignItems: "center",
+              justifyContent: "space-between",
+              marginTop: "12px",
+              color: "#8b8fa3",
+              fontSize: "14px",
+              fontWeight: 600
+            }}>
+              Auto cash out at
+              <input
+                type="number"
+                min={1.01}
+                step={0.01}
+                value={autoCashOut}
+                onChange={(e) => setAutoCashOut(Math.max(1.01, Number(e.target.value)))}
+                style={{
+                  background: "#1e2130",
+                  border: "2px solid #3a3f5c",
+                  borderRadius: "6px",
+                  color: "#fff",
+                  fontSize: "14px",
+                  fontWeight: 600,
+                  width: "90px",
+                  padding: "6px 8px",
+                  textAlign: "center",
+                  outline: "none"
+                }}
+              />
+            </div>
+          )}
         </div>
 
         {/* Action Button */}
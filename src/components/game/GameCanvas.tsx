@@ -4,9 +4,17 @@ import { useBalloonFlyContext } from "../../contexts/BalloonFlyContext";
 const GameCanvas: React.FC = () => {
   const { currentMultiplier, isFlying, currentRound } = useBalloonFlyContext();
   
-  const balloonEmoji = isFlying ? "🎈" : currentRound?.status === "Ended" ? "💥" : "🎈";
-  const statusMessage = !isFlying && currentRound?.status === "Waiting" 
-    ? "🎈 Waiting for bets..." 
+  const status = currentRound?.status.tag;
+  const crashed = status === "Ended" || (status === "InProgress" && !isFlying);
+  const balloonEmoji = isFlying ? "🎈" : crashed ? "💥" : "🎈";
+  const statusMessage = isFlying
+    ? ""
+    : status === "Waiting"
+    ? "🎈 Waiting for bets..."
+    : status === "Revealing"
+    ? "🔒 Betting closed, revealing seeds..."
+    : status === "Cancelled"
+    ? "↩️ Round cancelled, bets can be refunded"
     : "";
 
   const getMultiplierColor = (mult: number) => {
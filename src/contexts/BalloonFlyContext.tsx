@@ -10,7 +10,7 @@ interface BalloonFlyContextType {
   pool: Pool | null;
   loading: boolean;
   error: string | null;
-  placeBet: (amount: number) => Promise<void>;
+  placeBet: (amount: number, autoCashOut?: number) => Promise<void>;
   cashOut: () => Promise<void>;
   formatXLM: (stroops: bigint) => string;
   multiplierToNumber: (mult: bigint) => number;
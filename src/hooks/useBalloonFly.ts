@@ -1,50 +1,33 @@
 import { useState, useEffect, useCallback } from "react";
 import { useWallet } from "./useWallet";
 import balloonFlyClient from "../contracts/balloonfly";
+import type {
+  Round,
+  Bet,
+  Pool,
+  RoundStatus,
+  BetStatus,
+} from "../../packages/balloonfly/dist/index";
 
-// Types matching the Rust contract
-export enum RoundStatus {
-  Waiting = "Waiting",
-  InProgress = "InProgress",
-  Ended = "Ended",
-}
+// Types generated from the contract spec
+export type { Round, Bet, Pool, RoundStatus, BetStatus };
 
-export enum BetStatus {
-  Active = "Active",
-  CashedOut = "CashedOut",
-  Lost = "Lost",
-}
+// Growth of the multiplier curve per second, as in the contract's `curve` module
+const GROWTH_PER_SECOND = 1.06;
 
-export interface Round {
-  id: bigint;
-  status: RoundStatus;
-  server_seed_hash: Buffer;
-  crash_multiplier: bigint;
-  created_at: bigint;
-  started_at: bigint;
-  ended_at: bigint;
-  total_bet_amount: bigint;
-  total_payout: bigint;
-  bet_count: number;
-  client_seeds: Buffer[];
-}
+// How often the current round and the player's bets are refreshed
+const POLL_INTERVAL = 2000;
 
-export interface Bet {
-  id: bigint;
-  round_id: bigint;
-  player: string;
-  amount: bigint;
-  cash_out_multiplier: bigint;
-  payout: bigint;
-  status: BetStatus;
-  timestamp: bigint;
-}
+// Multiplier the curve has reached in a started round (1.0 = 1.00x)
+const curveMultiplier = (round: Round): number => {
+  const elapsed = Math.max(0, Date.now() / 1000 - Number(round.started_at));
+  return Math.pow(GROWTH_PER_SECOND, elapsed);
+};
 
-export interface Pool {
-  total_bets: bigint;
-  total_payouts: bigint;
-  total_house_earnings: bigint;
-}
+// A round stays in progress until finalized, but stops flying at its crash point
+const isRoundFlying = (round: Round): boolean =>
+  round.status.tag === "InProgress" &&
+  curveMultiplier(round) < Number(round.crash_multiplier) / 100;
 
 interface UseBalloonFlyReturn {
   // State
@@ -57,17 +40,17 @@ interface UseBalloonFlyReturn {
   error: string | null;
 
   // Actions
-  placeBet: (amount: number) => Promise<void>;
+  placeBet: (amount: number, autoCashOut?: number) => Promise<void>;
   cashOut: () => Promise<void>;
-  
+
   // Utilities
   formatXLM: (stroops: bigint) => string;
   multiplierToNumber: (mult: bigint) => number;
 }
 
 export const useBalloonFly = (): UseBalloonFlyReturn => {
-  const { address } = useWallet();
-  
+  const { address, signTransaction } = useWallet();
+
   const [currentRound, setCurrentRound] = useState<Round | null>(null);
   const [currentMultiplier, setCurrentMultiplier] = useState(1.0);
   const [isFlying, setIsFlying] = useState(false);
@@ -89,43 +72,66 @@ export const useBalloonFly = (): UseBalloonFlyReturn => {
   const fetchPool = useCallback(async () => {
     try {
       const poolData = await balloonFlyClient.get_pool();
-      if (poolData.result) {
-        setPool(poolData.result as unknown as Pool);
-      }
+      setPool(poolData.result);
     } catch (err) {
       console.error("Error fetching pool:", err);
     }
   }, []);
 
-  // Fetch current round
-  const fetchCurrentRound = useCallback(async (roundId: bigint) => {
+  // Fetch the player's latest bet in a round
+  const fetchUserBet = useCallback(async (roundId: bigint) => {
+    if (!address) {
+      setUserBet(null);
+      return;
+    }
+    try {
+      const betsData = await balloonFlyClient.get_player_round_bets({
+        player: address,
+        round_id: roundId,
+      });
+      const bets = betsData.result;
+      setUserBet(bets.length > 0 ? bets[bets.length - 1] : null);
+    } catch (err) {
+      console.error("Error fetching bets:", err);
+    }
+  }, [address]);
+
+  // Fetch the round the contract is running. Once it closes, keep showing its
+  // final state until the operator opens the next one.
+  const fetchCurrentRound = useCallback(async () => {
     try {
-      const roundData = await balloonFlyClient.get_round({ round_id: roundId });
-      if (roundData.result) {
-        const round = roundData.result as unknown as Round;
-        setCurrentRound(round);
-        
-        // Update flying state based on round status
-        if (round.status === RoundStatus.InProgress) {
-          setIsFlying(true);
-        } else {
-          setIsFlying(false);
+      const roundData = await balloonFlyClient.current_round();
+      let round = roundData.result ?? null;
+      if (!round) {
+        const previousId = currentRound?.id;
+        if (previousId === undefined) {
+          return;
         }
+        const endedData = await balloonFlyClient.get_round({ round_id: previousId });
+        round = endedData.result.unwrap();
       }
+
+      setCurrentRound(round);
+      setIsFlying(isRoundFlying(round));
+      await fetchUserBet(round.id);
     } catch (err) {
       console.error("Error fetching round:", err);
     }
-  }, []);
+  }, [currentRound?.id, fetchUserBet]);
 
-  // Place bet
-  const placeBet = useCallback(async (amount: number) => {
-    if (!address) {
-      setError("Please connect your wallet");
-      return;
+  // Simulate as the connected account, then sign with the wallet and send
+  const prepare = useCallback(() => {
+    if (!address || !signTransaction) {
+      throw new Error("Please connect your wallet");
     }
+    balloonFlyClient.options.publicKey = address;
+    return { address, signTransaction };
+  }, [address, signTransaction]);
 
-    if (!currentRound) {
-      setError("No active round");
+  // Place bet, optionally cashed out automatically at `autoCashOut` (e.g. 2.5 for 2.50x)
+  const placeBet = useCallback(async (amount: number, autoCashOut?: number) => {
+    if (!currentRound || currentRound.status.tag !== "Waiting") {
+      setError("No round is taking bets");
       return;
     }
 
@@ -133,48 +139,36 @@ export const useBalloonFly = (): UseBalloonFlyReturn => {
     setError(null);
 
     try {
+      const { address: player, signTransaction: sign } = prepare();
       const amountInStroops = BigInt(Math.floor(amount * 10_000_000));
-      
+
       // Generate random client seed
       const clientSeed = new Uint8Array(32);
       crypto.getRandomValues(clientSeed);
-      
-      const result = await balloonFlyClient.place_bet({
-        player: address,
+
+      const tx = await balloonFlyClient.place_bet({
+        player,
         round_id: currentRound.id,
         amount: amountInStroops,
         client_seed: Buffer.from(clientSeed),
+        auto_cash_out:
+          autoCashOut !== undefined ? BigInt(Math.round(autoCashOut * 100)) : undefined,
       });
+      const sent = await tx.signAndSend({ signTransaction: sign });
+      console.log("Bet placed:", sent.result.unwrap());
 
-      console.log("Bet placed:", result);
-      
-      // Fetch the bet details
-      if (result.result) {
-        const betId = result.result as unknown as bigint;
-        const betData = await balloonFlyClient.get_bet({ bet_id: betId });
-        if (betData.result) {
-          setUserBet(betData.result as unknown as Bet);
-        }
-      }
-
-      // Refresh round data
-      await fetchCurrentRound(currentRound.id);
+      await fetchCurrentRound();
     } catch (err: any) {
       console.error("Error placing bet:", err);
       setError(err.message || "Failed to place bet");
     } finally {
       setLoading(false);
     }
-  }, [address, currentRound, fetchCurrentRound]);
+  }, [currentRound, prepare, fetchCurrentRound]);
 
-  // Cash out
+  // Cash out at the multiplier the contract computes when the call lands
   const cashOut = useCallback(async () => {
-    if (!address) {
-      setError("Please connect your wallet");
-      return;
-    }
-
-    if (!userBet || userBet.status !== BetStatus.Active) {
+    if (!userBet || userBet.status.tag !== "Active") {
       setError("No active bet to cash out");
       return;
     }
@@ -188,65 +182,61 @@ export const useBalloonFly = (): UseBalloonFlyReturn => {
     setError(null);
 
     try {
-      const currentMultiplierInContract = BigInt(Math.floor(currentMultiplier * 100));
-      
-      const result = await balloonFlyClient.cash_out({
-        player: address,
+      const { address: player, signTransaction: sign } = prepare();
+      const tx = await balloonFlyClient.cash_out({
+        player,
         bet_id: userBet.id,
-        current_multiplier: currentMultiplierInContract,
       });
+      const sent = await tx.signAndSend({ signTransaction: sign });
+      console.log("Cashed out:", sent.result.unwrap());
 
-      console.log("Cashed out:", result);
-      
-      // Refresh bet data
-      const betData = await balloonFlyClient.get_bet({ bet_id: userBet.id });
-      if (betData.result) {
-        setUserBet(betData.result as unknown as Bet);
-      }
-
-      // Refresh round data
-      if (currentRound) {
-        await fetchCurrentRound(currentRound.id);
-      }
+      await fetchCurrentRound();
     } catch (err: any) {
       console.error("Error cashing out:", err);
       setError(err.message || "Failed to cash out");
     } finally {
       setLoading(false);
     }
-  }, [address, userBet, isFlying, currentMultiplier, currentRound, fetchCurrentRound]);
+  }, [userBet, isFlying, prepare, fetchCurrentRound]);
 
-  // Simulate multiplier increase (in production, this would come from contract events)
+  // Follow the contract's curve from the round's start until its crash point
   useEffect(() => {
-    if (!isFlying) {
-      setCurrentMultiplier(1.0);
+    if (!isFlying || !currentRound) {
+      // Crashed rounds keep showing their crash point
+      const crashed = currentRound && currentRound.crash_multiplier > 0n;
+      setCurrentMultiplier(crashed ? multiplierToNumber(currentRound.crash_multiplier) : 1.0);
       return;
     }
 
+    const crashMult = multiplierToNumber(currentRound.crash_multiplier);
+
     const interval = setInterval(() => {
-      setCurrentMultiplier((prev) => {
-        const newMult = prev + 0.01;
-        
-        // Check if crashed
-        if (currentRound && currentRound.crash_multiplier > 0) {
-          const crashMult = multiplierToNumber(currentRound.crash_multiplier);
-          if (newMult >= crashMult) {
-            setIsFlying(false);
-            return crashMult;
-          }
-        }
-        
-        return newMult;
-      });
+      const newMult = curveMultiplier(currentRound);
+
+      // Check if crashed
+      if (newMult >= crashMult) {
+        setCurrentMultiplier(crashMult);
+        setIsFlying(false);
+        return;
+      }
+      setCurrentMultiplier(newMult);
     }, 100);
 
     return () => clearInterval(interval);
   }, [isFlying, currentRound, multiplierToNumber]);
 
+  // Poll the current round and the player's bet in it
+  useEffect(() => {
+    fetchCurrentRound();
+
+    const interval = setInterval(fetchCurrentRound, POLL_INTERVAL);
+    return () => clearInterval(interval);
+  }, [fetchCurrentRound]);
+
   // Fetch pool on mount
   useEffect(() => {
     fetchPool();
-    
+
     // Refresh pool every 10 seconds
     const interval = setInterval(fetchPool, 10000);
     return () => clearInterval(interval);
@@ -266,4 +256,3 @@ export const useBalloonFly = (): UseBalloonFlyReturn => {
     multiplierToNumber,
   };
 };
-